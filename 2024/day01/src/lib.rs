@@ -0,0 +1,109 @@
+use std::{io, iter};
+use std::error::Error;
+use std::collections::{BTreeMap, HashMap};
+use runner::{AnswerKind, DayId, InputShape, Puzzle, Solution};
+
+pub struct Day01;
+impl Solution for Day01 {
+    const ID: DayId = DayId::new(2024, 1);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Historian Hysteria",
+        parts: &[AnswerKind::Integer, AnswerKind::Integer],
+        parameters: &[],
+        input: InputShape::Lines,
+    };
+    type Input<'a> = Vec<(i32, i32)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        parse_bytes(input.as_bytes())
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Option<String> {
+        Some(solve_part_2(input).to_string())
+    }
+}
+
+pub fn solve_part_1(input: &[(i32, i32)]) -> i64 {
+    let mut left:  Vec<i32>;
+    let mut right: Vec<i32>;
+    (left, right) = input.iter().cloned().unzip();
+
+    left.sort();
+    right.sort();
+
+    iter::zip(left, right)
+        .map(|pair| (i64::from(pair.0) - i64::from(pair.1)).abs())
+        .sum()
+}
+
+pub fn solve_part_2(input: &[(i32, i32)]) -> i64 {
+    let mut right_count = HashMap::new();
+    for (_, right_num) in input {
+        *right_count.entry(right_num).or_insert(0) += 1;
+    }
+
+    input.iter()
+        .map(|(left_num, _)|
+            i64::from(*left_num) * right_count.get(&left_num).unwrap_or(&0)
+        )
+        .sum()
+}
+
+/// Solves both parts in one pass over the input. Only a count of each
+/// distinct number is kept, so memory doesn't grow with the number of lines.
+pub fn solve_stream(input: impl io::BufRead) -> Result<(i64, i64), Box<dyn Error>> {
+    let mut left = BTreeMap::new();
+    let mut right = BTreeMap::new();
+    parsing::for_each_line(input, |line| {
+        let (left_num, right_num) = parse_line(line)?;
+        *left.entry(left_num).or_insert(0u64) += 1;
+        *right.entry(right_num).or_insert(0u64) += 1;
+        Ok(())
+    })?;
+
+    // Pairs up the smallest numbers left in each list, as many at a time as
+    // both lists have copies of.
+    let mut part_1 = 0;
+    let mut lefts = left.iter().map(|(&num, &count)| (num, count));
+    let mut rights = right.iter().map(|(&num, &count)| (num, count));
+    let (mut l, mut r) = (lefts.next(), rights.next());
+    while let (Some((left_num, left_count)), Some((right_num, right_count))) = (l, r) {
+        let pairs = left_count.min(right_count);
+        part_1 += (i64::from(left_num) - i64::from(right_num)).abs() * pairs as i64;
+        l = if left_count == pairs {lefts.next()} else {Some((left_num, left_count - pairs))};
+        r = if right_count == pairs {rights.next()} else {Some((right_num, right_count - pairs))};
+    }
+
+    let part_2 = left.iter()
+        .map(|(num, count)| i64::from(*num) * (count * right.get(num).unwrap_or(&0)) as i64)
+        .sum();
+
+    Ok((part_1, part_2))
+}
+
+fn parse_line(line: &str) -> Result<(i32, i32), Box<dyn Error>> {
+    let mut split = line.split_whitespace();
+    let first_num  = split.next().ok_or("Missing number in line")?.parse()?;
+    let second_num = split.next().ok_or("Missing number in line")?.parse()?;
+    Ok((first_num, second_num))
+}
+
+/// The same as `parse_line` for ASCII lines, without allocating.
+fn parse_line_bytes(line: &[u8]) -> Result<(i32, i32), Box<dyn Error>> {
+    let mut words = parsing::words(line);
+    let first_num  = parsing::int(words.next().ok_or("Missing number in line")?)?;
+    let second_num = parsing::int(words.next().ok_or("Missing number in line")?)?;
+    Ok((first_num, second_num))
+}
+
+fn parse_bytes(input: &[u8]) -> Result<Vec<(i32, i32)>, Box<dyn Error>> {
+    parsing::fast_lines(input, parse_line_bytes, parse_line)
+}
+
+pub fn parse_input(input: impl io::Read) -> Result<Vec<(i32, i32)>, Box<dyn Error>> {
+    parse_bytes(&parsing::read_all(input)?)
+}
@@ -0,0 +1,129 @@
+use std::io;
+use std::error::Error;
+use runner::{AnswerKind, DayId, InputShape, Puzzle, Solution, Variant};
+
+pub struct Day02;
+impl Solution for Day02 {
+    const ID: DayId = DayId::new(2024, 2);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Red-Nosed Reports",
+        parts: &[AnswerKind::Integer, AnswerKind::Integer],
+        parameters: &[],
+        input: InputShape::Items{separator: " "},
+    };
+    type Input<'a> = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        parse_bytes(input.as_bytes())
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Option<String> {
+        Some(solve_part_2(input).to_string())
+    }
+
+    fn variants() -> Vec<Variant<Self>> {
+        vec![
+            Variant{name: "brute-force", part: 2, solve: |input| solve_part_2_brute_force(input).to_string()},
+        ]
+    }
+}
+
+pub fn solve_part_1(reports: &[Vec<i32>]) -> usize {
+    reports.iter()
+        .filter(|report| is_safe(report))
+        .count()
+}
+
+pub fn solve_part_2(reports: &[Vec<i32>]) -> usize {
+    reports.iter()
+        .filter(|report| is_safe_with_dampener(report))
+        .count()
+}
+
+/// Like [`solve_part_2`], but tries removing every level in turn.
+pub fn solve_part_2_brute_force(reports: &[Vec<i32>]) -> usize {
+    reports.iter()
+        .filter(|report| {
+            is_safe(report) || (0..report.len()).any(|i| is_safe(&[&report[..i], &report[i+1..]].concat()))
+        })
+        .count()
+}
+
+/// Solves both parts in one pass over the input, one report at a time.
+pub fn solve_stream(input: impl io::BufRead) -> Result<(usize, usize), Box<dyn Error>> {
+    let mut answers = (0, 0);
+    parsing::for_each_line(input, |line| {
+        let report = parse_line(line)?;
+        answers.0 += usize::from(is_safe(&report));
+        answers.1 += usize::from(is_safe_with_dampener(&report));
+        Ok(())
+    })?;
+    Ok(answers)
+}
+
+/// The change from one level to the next. Levels are `i32`s, so their
+/// difference may not be.
+fn diff(from: i32, to: i32) -> i64 {
+    i64::from(to) - i64::from(from)
+}
+
+/// Whether a report is safe. Empty reports don't count.
+fn is_safe(report: &[i32]) -> bool {
+    let sign = match report.len() {
+        0 => return false,
+        1 => return true,
+        _ => diff(report[0], report[1]).signum(),
+    };
+
+    report.windows(2)
+        .map(|win| diff(win[0], win[1]))
+        .all(|d| (d.signum() == sign) && (1..=3).contains(&d.abs()))
+}
+
+/// Whether a report is safe after removing at most one level. Empty reports
+/// don't count.
+fn is_safe_with_dampener(report: &[i32]) -> bool {
+    fn is_safe(report: &[i32], diff_range: std::ops::Range<i64>) -> bool {
+        let n = report.len();
+        let index_of_first_error =
+            report.windows(2).enumerate()
+                .find(|(_, win)| !diff_range.contains(&diff(win[0], win[1])))
+                .map(|(i, _)| i + 1)
+                .unwrap_or(n);
+        if index_of_first_error >= n-1 {return true;}
+        let i = index_of_first_error;
+
+        let can_remove_ith = diff_range.contains(&diff(report[i-1], report[i+1]));
+        let can_remove_prev = (i < 2 || diff_range.contains(&diff(report[i-2], report[i])))
+            && diff_range.contains(&diff(report[i], report[i+1]));
+
+        let is_safe_after_first_error =
+            report[i+1 .. ].windows(2)
+                .map(|win| diff(win[0], win[1]))
+                .all(|d| diff_range.contains(&d));
+
+        is_safe_after_first_error && (can_remove_ith || can_remove_prev)
+    }
+
+    !report.is_empty() && (is_safe(report, 1..4) || is_safe(report, -3..0))
+}
+
+fn parse_line(line: &str) -> Result<Vec<i32>, std::num::ParseIntError> {
+    line.split_whitespace().map(str::parse::<i32>).collect()
+}
+
+fn parse_bytes(input: &[u8]) -> Result<Vec<Vec<i32>>, Box<dyn Error>> {
+    parsing::fast_lines(
+        input,
+        |line| Ok( parsing::words(line).map(parsing::int).collect::<Result<_,_>>()? ),
+        |line| Ok( parse_line(line)? ),
+    )
+}
+
+pub fn parse_input(input: impl io::Read) -> Result<Vec<Vec<i32>>, Box<dyn Error>> {
+    parse_bytes(&parsing::read_all(input)?)
+}
@@ -0,0 +1,104 @@
+use std::io;
+use std::error::Error;
+use regex::Regex;
+use runner::{AnswerKind, DayId, InputShape, Puzzle, Solution};
+
+pub struct Day03;
+impl Solution for Day03 {
+    const ID: DayId = DayId::new(2024, 3);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Mull It Over",
+        parts: &[AnswerKind::Integer, AnswerKind::Integer],
+        parameters: &[],
+        input: InputShape::Lines,
+    };
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        Ok(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Option<String> {
+        Some(solve_part_2(input).to_string())
+    }
+}
+
+pub fn solve_part_1(input: &str) -> u64 {
+    let re = Regex::new(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)").unwrap();
+
+    let mut answer = 0;
+    for (_, [x, y]) in re.captures_iter(input).map(|c| c.extract()) {
+        answer += number(x.as_bytes()) * number(y.as_bytes());
+    }
+    answer
+}
+
+pub fn solve_part_2(input: &str) -> u64 {
+    let re = Regex::new(r"do\(\)|don't\(\)|mul\(([0-9]{1,3}),([0-9]{1,3})\)").unwrap();
+
+    let mut answer = 0;
+    let mut enabled = true;
+    for capture in re.captures_iter(input) {
+        match capture.get(0).unwrap().as_str() {
+            "do()" => enabled = true,
+            "don't()" => enabled = false,
+            _ => if enabled {
+                answer += number(capture[1].as_bytes()) * number(capture[2].as_bytes());
+            }
+        }
+    }
+    answer
+}
+
+/// The value of an operand, which the regexes only match as 1 to 3 digits.
+fn number(digits: &[u8]) -> u64 {
+    digits.iter().fold(0, |n, digit| n * 10 + u64::from(digit - b'0'))
+}
+
+/// Solves both parts in one pass over the input, reading it a buffer at a
+/// time. An instruction can be split between two reads, so the end of each
+/// buffer that could still be the start of one is kept for the next.
+pub fn solve_stream(mut input: impl io::BufRead) -> Result<(u64, u64), Box<dyn Error>> {
+    // The longest instruction is `mul(123,456)`.
+    const MAX_INSTRUCTION_LEN: usize = 12;
+    let re = regex::bytes::Regex::new(r"do\(\)|don't\(\)|mul\(([0-9]{1,3}),([0-9]{1,3})\)").unwrap();
+
+    let mut answers = (0, 0);
+    let mut enabled = true;
+    let mut buffer = vec![];
+    loop {
+        let read = input.fill_buf()?;
+        if read.is_empty() {
+            break;
+        }
+        buffer.extend_from_slice(read);
+        let len = read.len();
+        input.consume(len);
+
+        let mut end = 0;
+        for capture in re.captures_iter(&buffer) {
+            match capture.get(0).unwrap().as_bytes() {
+                b"do()" => enabled = true,
+                b"don't()" => enabled = false,
+                _ => {
+                    let product = number(&capture[1]) * number(&capture[2]);
+                    answers.0 += product;
+                    if enabled {
+                        answers.1 += product;
+                    }
+                }
+            }
+            end = capture.get(0).unwrap().end();
+        }
+
+        // Whatever follows the last match and is too short to hold a whole
+        // instruction may be the start of one.
+        let keep_from = end.max(buffer.len().saturating_sub(MAX_INSTRUCTION_LEN - 1));
+        buffer.drain(..keep_from);
+    }
+    Ok(answers)
+}
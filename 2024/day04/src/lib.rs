@@ -0,0 +1,83 @@
+use std::error::Error;
+use itertools::izip;
+use runner::{AnswerKind, DayId, InputShape, Puzzle, Solution};
+
+pub struct Day04;
+impl Solution for Day04 {
+    const ID: DayId = DayId::new(2024, 4);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Ceres Search",
+        parts: &[AnswerKind::Integer, AnswerKind::Integer],
+        parameters: &[],
+        input: InputShape::Grid,
+    };
+    type Input<'a> = Vec<&'a [u8]>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        Ok(grid::rows(input))
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Option<String> {
+        Some(solve_part_2(input).to_string())
+    }
+}
+
+pub fn solve_part_1(grid: &[&[u8]]) -> usize {
+    // Rows can have different lengths, so a diagonal only goes as far as the
+    // shortest of its rows.
+    fn tail(row: &[u8], skip: usize) -> &[u8] {
+        row.get(skip..).unwrap_or_default()
+    }
+
+    let horizontal_matches = grid.iter()
+        .flat_map(|row| row.windows(4))
+        .filter(|word| word == b"XMAS" || word == b"SAMX")
+        .count();
+
+    let mut vertical_matches = 0;
+    let mut diagonal_matches = 0;
+    for rows in grid.windows(4) {
+        for col in izip!(rows[0], rows[1], rows[2], rows[3]) {
+            let word: [u8; 4]= [*col.0, *col.1, *col.2, *col.3];
+            if (&word == b"XMAS") || (&word == b"SAMX") {
+                vertical_matches += 1;
+            }
+        }
+        for diag in izip!(rows[0], tail(rows[1], 1), tail(rows[2], 2), tail(rows[3], 3)) {
+            let word: [u8; 4]= [*diag.0, *diag.1, *diag.2, *diag.3];
+            if (&word == b"XMAS") || (&word == b"SAMX") {
+                diagonal_matches += 1;
+            }
+        }
+        for diag in izip!(rows[3], tail(rows[2], 1), tail(rows[1], 2), tail(rows[0], 3)) {
+            let word: [u8; 4]= [*diag.0, *diag.1, *diag.2, *diag.3];
+            if (&word == b"XMAS") || (&word == b"SAMX") {
+                diagonal_matches += 1;
+            }
+        }
+    }
+
+    horizontal_matches + vertical_matches + diagonal_matches
+}
+
+pub fn solve_part_2(grid: &[&[u8]]) -> usize {
+    let mut matches = 0;
+    for rows in grid.windows(3) {
+        for subgrid in izip!(rows[0].windows(3), rows[1].windows(3), rows[2].windows(3)) {
+            if subgrid.1[1] != b'A' {
+                continue;
+            }
+
+            let corners = [subgrid.0[0], subgrid.0[2], subgrid.2[2], subgrid.2[0]];
+            if (&corners == b"MMSS") || (&corners == b"MSSM") || (&corners == b"SSMM") || (&corners == b"SMMS") {
+                matches += 1;
+            }
+        }
+    }
+
+    matches
+}
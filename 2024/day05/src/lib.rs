@@ -0,0 +1,110 @@
+use std::io;
+use std::error::Error;
+use std::collections::{HashMap, HashSet};
+use runner::{AnswerKind, DayId, InputShape, Puzzle, Solution};
+use tracing::debug;
+
+pub struct Day05;
+impl Solution for Day05 {
+    const ID: DayId = DayId::new(2024, 5);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Print Queue",
+        parts: &[AnswerKind::Integer, AnswerKind::Integer],
+        parameters: &[],
+        input: InputShape::Sections(&[InputShape::Lines, InputShape::Items{separator: ","}]),
+    };
+    type Input<'a> = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        parse_input(input.as_bytes())
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Option<String> {
+        Some(solve_part_2(input).to_string())
+    }
+}
+
+pub type RulesMap = HashMap<i32, HashSet<i32>>;
+pub type PuzzleInput = (RulesMap, Vec<Vec<i32>>);
+
+pub fn solve_part_1(input: &PuzzleInput) -> i32 {
+    let (rules, updates) = input;
+
+    updates.iter()
+        .filter(|update| is_ordered_correctly(update, rules))
+        .map(|update| update[ update.len()/2 ])
+        .sum()
+}
+
+pub fn solve_part_2(input: &PuzzleInput) -> i32 {
+    let (rules, updates) = input;
+
+    updates.iter()
+        .filter(|update| !is_ordered_correctly(update, rules))
+        .map(|update| sort_pages(update.clone(), rules))
+        .map(|update| update[ update.len()/2 ])
+        .sum()
+}
+
+fn is_ordered_correctly(update: &[i32], rules: &RulesMap) -> bool {
+    let mut seen = HashSet::new();
+    for page in update {
+        seen.insert(*page);
+
+        let Some(succesors) = rules.get(page) else {continue;};
+        if let Some(succesor) = succesors.intersection(&seen).next() {
+            debug!(?update, rule = %format_args!("{page}|{succesor}"), "update is mis-ordered");
+            return false;
+        }
+    }
+
+    true
+}
+
+fn sort_pages(mut pages: Vec<i32>, rules: &RulesMap) -> Vec<i32> {
+    pages.sort_by(|a, b| {
+        let empty_set = HashSet::new();
+        if rules.get(a).unwrap_or(&empty_set).contains(b) {
+            std::cmp::Ordering::Less
+        } else if rules.get(b).unwrap_or(&empty_set).contains(a) {
+            std::cmp::Ordering::Greater
+        } else {
+            std::cmp::Ordering::Equal
+        }
+    });
+    pages
+}
+
+pub fn parse_input(input: impl io::BufRead) -> Result<PuzzleInput, Box<dyn Error>> {
+    let mut rules = RulesMap::new();
+    let mut updates = vec![];
+
+    let mut is_first_section = true;
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            is_first_section = false;
+            continue;
+        }
+
+        if is_first_section {
+            let (x,y) = line.split_once('|')
+                .ok_or("Invalid format: missing '|' character")?;
+    
+            let x: i32 = x.parse()?;
+            let y: i32 = y.parse()?;
+            rules.entry(x).or_default().insert(y);
+        } else {
+            let pages: Vec<i32> = line.split(',')
+                .map(str::parse::<i32>)
+                .collect::<Result<_, _>>()?;
+            updates.push(pages);
+        }
+    }
+
+    Ok((rules, updates))
+}
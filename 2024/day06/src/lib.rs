@@ -0,0 +1,84 @@
+use std::error::Error;
+
+mod walk;
+pub use walk::WalkIterator;
+use geometry::Direction;
+use grid::CellSet;
+use runner::{AnswerKind, DayId, InputShape, Puzzle, Solution, TimedOut};
+
+pub struct Day06;
+impl Solution for Day06 {
+    const ID: DayId = DayId::new(2024, 6);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Guard Gallivant",
+        parts: &[AnswerKind::Integer, AnswerKind::Integer],
+        parameters: &[],
+        input: InputShape::Grid,
+    };
+    type Input<'a> = Vec<&'a [u8]>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        Ok(grid::rows(input))
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        solve_part_1(input).map_or_else(runner::fail, |n| n.to_string())
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Option<String> {
+        Some(solve_part_2(input).map_or_else(runner::fail, |n| n.to_string()))
+    }
+}
+
+
+/// The guard may walk in a loop forever, in which case this only stops when
+/// the part runs out of budget.
+pub fn solve_part_1(grid: &[&[u8]]) -> Result<usize, TimedOut> {
+    let Some(start) = grid::find(grid, &b'^') else {
+        return Ok(0);
+    };
+
+    let mut visited = CellSet::covering(grid);
+    for (i, j, _) in WalkIterator::new(grid, start, Direction::Up) {
+        runner::cancel::check()?;
+        visited.insert((i, j));
+    }
+    Ok(visited.len())
+}
+
+pub fn solve_part_2(grid: &[&[u8]]) -> Result<usize, TimedOut> {
+    let Some(start) = grid::find(grid, &b'^') else {
+        return Ok(0);
+    };
+
+    // Every cell on the guard's path is a candidate, the first time the
+    // guard steps onto it, from where it came from. The path is walked once
+    // to find them, so that they can be counted before they are checked.
+    let mut candidates = vec![];
+    let mut visited = CellSet::covering(grid);
+    visited.insert(start);
+    let mut prev = start;
+    for (i, j, dir) in WalkIterator::new(grid, start, Direction::Up).skip(1) {
+        runner::cancel::check()?;
+        if visited.insert((i, j)) {
+            candidates.push((prev, dir, (i, j)));
+        }
+        prev = (i, j);
+    }
+
+    // Progress is the cells on the path up to the one being checked, along
+    // with the starting cell, which can't take an obstacle.
+    let total = visited.len() as u64;
+    let mut answer = 0;
+    for (checked, &(prev, dir, obstacle)) in candidates.iter().enumerate() {
+        runner::cancel::check()?;
+        runner::progress::report(checked as u64 + 2, total);
+        let branched_path = WalkIterator::new(grid, prev, dir)
+            .with_added_obstacle(obstacle);
+        if branched_path.is_inifinite_loop() {
+            answer += 1;
+        }
+    }
+
+    Ok(answer)
+}
@@ -1,5 +1,8 @@
-use std::collections::HashSet;
+use geometry::Direction;
 
+/// Walks the guard through the grid, yielding each position and the
+/// direction the guard is facing there, until it leaves the grid.
+#[derive(Clone)]
 pub struct WalkIterator<'a> {
     grid: &'a [&'a [u8]],
     i: usize,
@@ -12,27 +15,12 @@ impl<'a> Iterator for WalkIterator<'a> {
     type Item = (usize, usize, Direction);
 
     fn next(&mut self) -> Option<Self::Item> {
-        let (i, j, dir) = (self.i, self.j, self.dir);
-        if i >= self.grid.len() || j >= self.grid[i].len() {
+        let state = (self.i, self.j, self.dir);
+        if !self.is_on_grid(state) {
             return None;
         }
-
-        for _ in 0..4 {
-            let (i2, j2) = self.dir.offset_coords(i, j);
-
-            let in_front = self.grid.get(i2).and_then(|row| row.get(j2));
-            if let Some(b'#') = in_front {
-                self.dir = dir.rotate();
-            } else if self.obstacles.contains(&(i2, j2)) {
-                self.dir = dir.rotate();
-            } else {
-                self.i = i2;
-                self.j = j2;
-                break;
-            }
-        }
-
-        Some((i, j, dir))
+        (self.i, self.j, self.dir) = self.step(state);
+        Some(state)
     }
 }
 
@@ -52,41 +40,40 @@ impl<'a> WalkIterator<'a> {
         self
     }
 
+    /// Whether the guard walks in a loop forever rather than leaving the
+    /// grid.
+    #[allow(clippy::wrong_self_convention)]
     pub fn is_inifinite_loop(self) -> bool {
-        let mut seen_states = HashSet::new();
-        for state in self {
-            if seen_states.contains(&state) {
-                return true;
-            }
-            seen_states.insert(state);
+        let start = (self.i, self.j, self.dir);
+        if !self.is_on_grid(start) {
+            return false;
         }
-        false
+        cycle::brent(start, |&state| {
+            let next = self.step(state);
+            self.is_on_grid(next).then_some(next)
+        }).is_some()
     }
-}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum Direction {Up, Right, Down, Left}
-impl Direction {
-    fn rotate(self) -> Self {
-        match self {
-            Self::Up => Self::Right,
-            Self::Right => Self::Down,
-            Self::Down => Self::Left,
-            Self::Left => Self::Up
-        }
+    fn is_on_grid(&self, (i, j, _): (usize, usize, Direction)) -> bool {
+        i < self.grid.len() && j < self.grid[i].len()
     }
 
-    fn offset_coords(self, i: usize, j: usize) -> (usize, usize) {
-        let i2 = match self {
-            Direction::Up   => i.overflowing_sub(1).0,
-            Direction::Down => i.overflowing_add(1).0,
-            _ => i
-        };
-        let j2 = match self {
-            Direction::Left  => j.overflowing_sub(1).0,
-            Direction::Right => j.overflowing_add(1).0,
-            _ => j
-        };
-        (i2, j2)
+    /// Where the guard is next, and which way it faces, turning right until
+    /// nothing is in front of it.
+    fn step(&self, (i, j, dir): (usize, usize, Direction)) -> (usize, usize, Direction) {
+        let mut facing = dir;
+        for _ in 0..4 {
+            let (i2, j2) = facing.offset_coords(i, j);
+
+            let in_front = self.grid.get(i2).and_then(|row| row.get(j2));
+            if let Some(b'#') = in_front {
+                facing = dir.rotate();
+            } else if self.obstacles.contains(&(i2, j2)) {
+                facing = dir.rotate();
+            } else {
+                return (i2, j2, facing);
+            }
+        }
+        (i, j, facing)
     }
 }
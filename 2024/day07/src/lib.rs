@@ -0,0 +1,205 @@
+use std::io;
+use std::error::Error;
+use runner::{AnswerKind, DayId, InputShape, Puzzle, Solution, Variant};
+use tracing::debug;
+
+pub type Equation = (u64, Vec<u64>);
+
+pub struct Day07;
+impl Solution for Day07 {
+    const ID: DayId = DayId::new(2024, 7);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Bridge Repair",
+        parts: &[AnswerKind::Integer, AnswerKind::Integer],
+        parameters: &[],
+        input: InputShape::Lines,
+    };
+    type Input<'a> = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        parse_bytes(input.as_bytes())
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Option<String> {
+        Some(solve_part_2(input).to_string())
+    }
+
+    fn variants() -> Vec<Variant<Self>> {
+        vec![
+            Variant{name: "recursive", part: 1, solve: |input| solve_part_1_recursive(input).to_string()},
+        ]
+    }
+}
+
+/// An intermediate result, or `None` if it is too big for a `u64`. Such a
+/// value can never equal a test value, but multiplying it by 0 still does, so
+/// it isn't an error.
+type Value = Option<u64>;
+
+fn add(a: Value, b: u64) -> Value {
+    a?.checked_add(b)
+}
+
+fn mul(a: Value, b: u64) -> Value {
+    if b == 0 {Some(0)} else {a?.checked_mul(b)}
+}
+
+fn concat(a: Value, b: u64) -> Value {
+    match a? {
+        0 => Some(b),
+        a => {
+            let digits = b.checked_ilog10().map_or(1, |log| log + 1);
+            a.checked_mul(10u64.checked_pow(digits)?)?.checked_add(b)
+        }
+    }
+}
+
+pub fn solve_part_1(input: &[Equation]) -> u128 {
+    input.iter()
+        .filter(|(val, nums)| is_possible(*val, nums))
+        .map(|(val, _)| u128::from(*val))
+        .sum()
+}
+
+/// Like [`solve_part_1`], but searches the operators recursively instead of
+/// trying every bitmask of them.
+pub fn solve_part_1_recursive(input: &[Equation]) -> u128 {
+    input.iter()
+        .filter(|(val, nums)| nums.split_first().is_some_and(|(&first, tail)| is_possible_recursive(Some(first), *val, tail)))
+        .map(|(val, _)| u128::from(*val))
+        .sum()
+}
+
+/// Whether some choice of `+` and `*` between `acc` and the operands gives
+/// `target`.
+fn is_possible_recursive(acc: Value, target: u64, operands: &[u64]) -> bool {
+    match operands.split_first() {
+        Some((&first, tail)) => {
+            is_possible_recursive(add(acc, first), target, tail) || is_possible_recursive(mul(acc, first), target, tail)
+        }
+        None => acc == Some(target),
+    }
+}
+
+pub fn solve_part_2(input: &[Equation]) -> u128 {
+    input.iter()
+        .filter(|(val, nums)| is_possible_with_concat(*val, nums))
+        .map(|(val, _)| u128::from(*val))
+        .sum()
+}
+
+/// Solves both parts in one pass over the input, one equation at a time.
+pub fn solve_stream(input: impl io::BufRead) -> Result<(u128, u128), Box<dyn Error>> {
+    let mut answers = (0, 0);
+    parsing::for_each_line(input, |line| {
+        let (val, nums) = parse_line(line)?;
+        if is_possible(val, &nums) {
+            answers.0 += u128::from(val);
+        }
+        if is_possible_with_concat(val, &nums) {
+            answers.1 += u128::from(val);
+        }
+        Ok(())
+    })?;
+    Ok(answers)
+}
+
+/// Whether some choice of `+` and `*` between the operands gives `val`.
+fn is_possible(val: u64, operands: &[u64]) -> bool {
+    fn eq_is_posible(val: u64, operands: &[u64]) -> bool {
+        let Some(&first) = operands.first() else {
+            return false;
+        };
+        let operands = &operands[1 .. ];
+        // There is a bit per operator, so longer equations don't fit in the
+        // flags and are searched recursively instead.
+        let Some(max_flagset) = u32::try_from(operands.len()).ok().and_then(|n| 1u128.checked_shl(n)) else {
+            return is_possible_recursive(Some(first), val, operands);
+        };
+
+        (0 .. max_flagset).any(|flags| {
+            let mut accumulator = Some(first);
+            for (i, operand) in operands.iter().enumerate() {
+                let bit = 1 & (flags >> i);
+                if bit == 0 {
+                    accumulator = add(accumulator, *operand);
+                } else {
+                    accumulator = mul(accumulator, *operand);
+                }
+            }
+            
+            accumulator == Some(val)
+        })
+    }
+
+    let possible = eq_is_posible(val, operands);
+    if !possible {
+        debug!(val, nums = ?operands, "equation cannot be made true");
+    }
+    possible
+}
+
+/// Whether some choice of `+`, `*` and `||` between the operands gives `val`.
+fn is_possible_with_concat(val: u64, operands: &[u64]) -> bool {
+    fn eq_is_posible(acc: Value, target: u64, operands: &[u64]) -> bool {
+        let Some((&first, tail)) = operands.split_first() else {
+            return acc == Some(target);
+        };
+        // Every operator only keeps or grows the value, except `*` by 0, so
+        // a value past the target is a dead end unless a 0 is still to come.
+        if acc.is_none_or(|acc| acc > target) && !operands.contains(&0) {
+            return false;
+        }
+        eq_is_posible(concat(acc, first), target, tail)
+            || eq_is_posible(add(acc, first), target, tail)
+            || eq_is_posible(mul(acc, first), target, tail)
+    }
+
+    let possible = if let Some(&first) = operands.first() {
+        eq_is_posible(Some(first), val, &operands[1..])
+    } else {
+        false
+    };
+    if !possible {
+        debug!(val, nums = ?operands, "equation cannot be made true");
+    }
+    possible
+}
+
+fn parse_line(line: &str) -> Result<Equation, Box<dyn Error>> {
+    let parts = line.split_once(':')
+        .ok_or("Invalid input format")?;
+
+    let value: u64 = parts.0.parse()?;
+    let operands = parts.1.split_whitespace()
+        .map(str::parse::<u64>)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((value, operands))
+}
+
+/// The same as `parse_line` for ASCII lines, without checking them for
+/// UTF-8 first.
+fn parse_line_bytes(line: &[u8]) -> Result<Equation, Box<dyn Error>> {
+    let colon = line.iter().position(|&b| b == b':')
+        .ok_or("Invalid input format")?;
+
+    let value = parsing::int(&line[..colon])?;
+    let operands = parsing::words(&line[colon+1..])
+        .map(parsing::int)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((value, operands))
+}
+
+fn parse_bytes(input: &[u8]) -> Result<Vec<Equation>, Box<dyn Error>> {
+    parsing::fast_lines(input, parse_line_bytes, parse_line)
+}
+
+pub fn parse_input(input: impl io::Read) -> Result<Vec<Equation>, Box<dyn Error>> {
+    parse_bytes(&parsing::read_all(input)?)
+}
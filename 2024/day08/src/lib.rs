@@ -0,0 +1,101 @@
+use std::error::Error;
+use std::collections::HashMap;
+use grid::{Pos, SparseGrid};
+use runner::{AnswerKind, DayId, InputShape, Puzzle, Solution};
+
+pub struct Day08;
+impl Solution for Day08 {
+    const ID: DayId = DayId::new(2024, 8);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Resonant Collinearity",
+        parts: &[AnswerKind::Integer, AnswerKind::Integer],
+        parameters: &[],
+        input: InputShape::Grid,
+    };
+    type Input<'a> = Vec<&'a [u8]>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        Ok(grid::rows(input))
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Option<String> {
+        Some(solve_part_2(input).to_string())
+    }
+}
+
+pub fn solve_part_1(map: &[&[u8]]) -> usize {
+    let mut antinodes = SparseGrid::new();
+
+    for (_freq, antennas) in find_all_antennas(map) {
+        for (i, &(r1, c1)) in antennas.iter().enumerate() {
+            for &(r2, c2) in antennas[i+1..].iter() {
+                let delta_r = r2 - r1;
+                let delta_c = c2 - c1;
+
+                for pos in [(r2 + delta_r, c2 + delta_c), (r1 - delta_r, c1 - delta_c)] {
+                    if is_in_bounds(pos, map) {
+                        antinodes.insert(pos, ());
+                    }
+                }
+            }
+        }
+    }
+    
+    antinodes.len()
+}
+
+pub fn solve_part_2(map: &[&[u8]]) -> usize {
+    let mut antinodes = SparseGrid::new();
+
+    for (_freq, antennas) in find_all_antennas(map) {
+        for (i, &(r1, c1)) in antennas.iter().enumerate() {
+            for &(r2, c2) in antennas[i+1..].iter() {
+                let delta_r = r2 - r1;
+                let delta_c = c2 - c1;
+
+                let (mut y, mut x) = (r2, c2);
+                while is_in_bounds((y, x), map) {
+                    antinodes.insert((y, x), ());
+                    y += delta_r;
+                    x += delta_c;
+                }
+
+                (y, x) = (r1, c1);
+                while is_in_bounds((y, x), map) {
+                    antinodes.insert((y, x), ());
+                    y -= delta_r;
+                    x -= delta_c;
+                }
+            }
+        }
+    }
+    
+    antinodes.len()
+}
+
+fn find_all_antennas(map: &[&[u8]]) -> HashMap<u8, Vec<Pos>> {
+    let mut antennas = HashMap::<u8, Vec<Pos>>::new();
+    for (i, row) in map.iter().enumerate() {
+        for (j, node) in row.iter().enumerate() {
+            if !node.is_ascii_alphanumeric() {
+                continue;
+            }
+            antennas.entry(*node).or_default()
+                .push((i as i64, j as i64));
+        }
+    }
+    antennas
+}
+
+/// Whether `(i, j)` is on the map. Antinodes can land above or left of it,
+/// at negative coordinates, which are never on it.
+fn is_in_bounds((i, j): Pos, map: &[&[u8]]) -> bool {
+    match (usize::try_from(i), usize::try_from(j)) {
+        (Ok(i), Ok(j)) => grid::get(map, (i, j)).is_some(),
+        _ => false,
+    }
+}
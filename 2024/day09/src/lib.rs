@@ -0,0 +1,145 @@
+use std::io;
+use std::error::Error;
+use std::iter::repeat_n;
+use runner::{AnswerKind, DayId, InputShape, Puzzle, Solution};
+
+pub struct Day09;
+impl Solution for Day09 {
+    const ID: DayId = DayId::new(2024, 9);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Disk Fragmenter",
+        parts: &[AnswerKind::Integer, AnswerKind::Integer],
+        parameters: &[],
+        input: InputShape::Items{separator: ""},
+    };
+    type Input<'a> = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        parse_input(input.as_bytes())
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Option<String> {
+        Some(solve_part_2(input).to_string())
+    }
+}
+
+pub fn solve_part_1(disk_map: &[u32]) -> usize {
+    let disk = disk_map
+        .chunks(2)
+        .enumerate()
+        .flat_map(|(id, chunk)| {
+            let file_len = chunk[0] as usize;
+            let space_len = *chunk.get(1).unwrap_or(&0) as usize;
+            
+            let file_blocks  = repeat_n(Some(id), file_len);
+            let empty_blocks = repeat_n(None, space_len);
+            file_blocks.chain(empty_blocks)
+        })
+        .collect::<Vec<_>>();
+
+    let mut left = 0;
+    let mut right = disk.len();
+    let mut checksum = 0;
+    while left < right {
+        let block = disk[left].or_else(|| {
+            right -= 1;
+            disk[right]
+        });
+        if let Some(id) = block {
+            checksum += left * id;
+            left += 1;
+        }
+    }
+
+    checksum
+}
+
+pub fn solve_part_2(disk_map: &[u32]) -> usize {
+    move_whole_files(disk_map)
+}
+
+/// Solves both parts, reading the disk map a buffer at a time. Compacting
+/// takes files from the end of the disk, so the whole map is kept, but as a
+/// byte per digit rather than as blocks.
+pub fn solve_stream(mut input: impl io::BufRead) -> Result<(usize, usize), Box<dyn Error>> {
+    let mut disk_map = vec![];
+    let mut at_end = false;
+    loop {
+        let read = input.fill_buf()?;
+        if read.is_empty() {
+            break;
+        }
+        for &byte in read {
+            match byte {
+                b'0'..=b'9' if !at_end => disk_map.push(byte - b'0'),
+                _ if byte.is_ascii_whitespace() => at_end = true,
+                _ => return Err("Invalid input format".into()),
+            }
+        }
+        let len = read.len();
+        input.consume(len);
+    }
+
+    Ok((move_blocks(&disk_map), move_whole_files(&disk_map)))
+}
+
+/// Part 1 without laying out the blocks: the compacted disk has as many
+/// blocks as there are file blocks, and each gap among them is filled with
+/// the next file block from the end.
+fn move_blocks(disk_map: &[u8]) -> usize {
+    let file_blocks: usize = disk_map.iter().step_by(2).map(|&len| len as usize).sum();
+    let blocks = disk_map.iter().enumerate()
+        .flat_map(|(i, &len)| repeat_n((i % 2 == 0).then_some(i / 2), len as usize));
+    let mut from_end = disk_map.iter().enumerate().step_by(2).rev()
+        .flat_map(|(i, &len)| repeat_n(i / 2, len as usize));
+
+    blocks.take(file_blocks)
+        .enumerate()
+        .map(|(pos, block)| pos * block.or_else(|| from_end.next()).unwrap_or(0))
+        .sum()
+}
+
+fn move_whole_files<T: Copy + Into<u64>>(disk_map: &[T]) -> usize {
+    let mut files = vec![];
+    let mut spaces = vec![];
+    let mut i = 0;
+    for (id, chunk) in disk_map.chunks(2).enumerate() {
+        let len = chunk[0].into() as usize;
+        files.push((id, i..i+len));
+        i += len;
+
+        let len = chunk.get(1).map_or(0, |&len| len.into() as usize);
+        spaces.push(i..i+len);
+        i += len;
+    }
+
+    let mut checksum = 0;
+    for (id, file_range) in files.into_iter().rev() {
+        let space = spaces.iter_mut()
+            .take_while(|space| space.end <= file_range.start)
+            .find(|space| space.len() >= file_range.len());
+
+        if let Some(space) = space {
+            let new_file_range = space.start .. space.start+file_range.len();
+            space.start += file_range.len();
+            checksum += id * new_file_range.sum::<usize>();
+        } else {
+            checksum += id * file_range.sum::<usize>();
+        }
+    }
+
+    checksum
+}
+
+pub fn parse_input(input: impl io::Read) -> Result<Vec<u32>, Box<dyn Error>> {
+    let input = io::read_to_string(input)?;
+
+    input.trim_end().chars()
+        .map(|ch| ch.to_digit(10))
+        .collect::<Option<_>>()
+        .ok_or("Invalid input format".into())
+}
@@ -0,0 +1,114 @@
+use std::io;
+use std::error::Error;
+use geometry::neighbors;
+use memo::Memo;
+use runner::{AnswerKind, DayId, InputShape, Puzzle, Solution};
+use tracing::debug;
+
+pub struct Day10;
+impl Solution for Day10 {
+    const ID: DayId = DayId::new(2024, 10);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Hoof It",
+        parts: &[AnswerKind::Integer, AnswerKind::Integer],
+        parameters: &[],
+        input: InputShape::Grid,
+    };
+    type Input<'a> = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        parse_input(input.as_bytes())
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Option<String> {
+        Some(solve_part_2(input).to_string())
+    }
+}
+
+pub fn solve_part_1(input: &[Vec<u32>]) -> usize {
+    /// The number of 9s reachable from `pos` that `trailhead` hasn't reached
+    /// yet, which are then marked as reached by it.
+    fn dfs(map: &[Vec<u32>], pos: (usize, usize), expected_num: u32, trailhead: usize, reached_by: &mut [Vec<usize>]) -> usize {
+        let (i, j) = pos;
+        let Some(current) = grid::get(map, (i, j)) else {
+            return 0;
+        };
+
+        if *current != expected_num {
+            return 0;
+        }
+        if *current >= 9 {
+            let last = std::mem::replace(&mut reached_by[i][j], trailhead);
+            return usize::from(last != trailhead);
+        }
+
+        neighbors(i, j).into_iter()
+            .map(|next| dfs(map, next, current+1, trailhead, reached_by))
+            .sum()
+    }
+
+    // Each 9 keeps the number of the last trailhead that reached it, so
+    // nothing needs to be cleared between trailheads. Trailheads are numbered
+    // from 1.
+    let mut reached_by: Vec<Vec<usize>> = input.iter().map(|row| vec![0; row.len()]).collect();
+    let mut trailheads = 0;
+    let mut total_score = 0;
+    for (i, row) in input.iter().enumerate() {
+        for (j, height) in row.iter().enumerate() {
+            if *height == 0 {
+                trailheads += 1;
+                total_score += dfs(input, (i, j), 0, trailheads, &mut reached_by);
+            }
+        }
+    }
+    total_score
+}
+
+pub fn solve_part_2(input: &[Vec<u32>]) -> usize {
+    /// The number of trails from `pos` up to a 9. Trails from different
+    /// trailheads meet, so this is memoised by position.
+    fn rating(map: &[Vec<u32>], pos: (usize, usize), memo: &mut Memo<(usize, usize), usize>) -> usize {
+        let (i, j) = pos;
+        let current = map[i][j];
+        if current >= 9 {
+            return 1;
+        }
+
+        memo.get_or_insert_with(pos, |memo| {
+            neighbors(i, j).into_iter()
+                .filter(|&next| grid::get(map, next) == Some(&(current+1)))
+                .map(|next| rating(map, next, memo))
+                .sum()
+        })
+    }
+
+    let mut memo = Memo::new();
+    let mut total_rating = 0;
+    for (i, row) in input.iter().enumerate() {
+        for (j, height) in row.iter().enumerate() {
+            if *height == 0 {
+                total_rating += rating(input, (i, j), &mut memo);
+            }
+        }
+    }
+    debug!(stats = %memo.stats(), "rated trailheads");
+    total_rating
+}
+
+pub fn parse_input(input: impl io::BufRead) -> Result<Vec<Vec<u32>>, Box<dyn Error>> {
+    let mut grid = vec![];
+    for line in input.lines() {
+        let line = line?;
+        let line = line.trim_end();
+        let row = line.chars()
+            .map(|c| c.to_digit(10))
+            .collect::<Option<_>>()
+            .ok_or("Non-digit character")?;
+        grid.push(row);
+    }
+    Ok(grid)
+}
@@ -0,0 +1,111 @@
+use std::fmt;
+use std::io;
+use std::error::Error;
+use memo::Memo;
+use runner::{AnswerKind, DayId, InputShape, Parameter, Puzzle, Solution};
+use tracing::debug;
+
+pub struct Day11;
+impl Solution for Day11 {
+    const ID: DayId = DayId::new(2024, 11);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Plutonian Pebbles",
+        parts: &[AnswerKind::Integer, AnswerKind::Integer],
+        parameters: &[
+            Parameter{name: "blinks_part_1", value: BLINKS_PART_1 as u64, description: "Blinks to count the stones after in part 1"},
+            Parameter{name: "blinks_part_2", value: BLINKS_PART_2 as u64, description: "Blinks to count the stones after in part 2"},
+        ],
+        input: InputShape::Items{separator: " "},
+    };
+    type Input<'a> = Vec<Stone>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        parse_input(input.as_bytes())
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        solve_part_1(input).map_or_else(runner::fail, |count| count.to_string())
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Option<String> {
+        Some(solve_part_2(input).map_or_else(runner::fail, |count| count.to_string()))
+    }
+}
+
+/// Stones are engraved with `u128`s, since a stone with an odd number of
+/// digits grows when multiplied by 2024. The number of stones doubles every
+/// few blinks, so it is counted in a `u128` too.
+pub type Stone = u128;
+
+/// A stone whose engraving would no longer fit in a [`Stone`] after being
+/// multiplied by 2024.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StoneTooBig(pub Stone);
+
+impl fmt::Display for StoneTooBig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Stone {} is too big to multiply by 2024", self.0)
+    }
+}
+
+impl Error for StoneTooBig {}
+
+/// Real inputs need a few hundred thousand entries at most, so this only
+/// limits how much memory a contrived input can take.
+const MEMO_CAPACITY: usize = 1 << 20;
+
+const BLINKS_PART_1: usize = 25;
+const BLINKS_PART_2: usize = 75;
+
+pub fn solve_part_1(stones: &[Stone]) -> Result<u128, StoneTooBig> {
+    count_all(stones, BLINKS_PART_1)
+}
+
+pub fn solve_part_2(stones: &[Stone]) -> Result<u128, StoneTooBig> {
+    count_all(stones, BLINKS_PART_2)
+}
+
+fn count_all(stones: &[Stone], blinks: usize) -> Result<u128, StoneTooBig> {
+    let mut memo = Memo::bounded(MEMO_CAPACITY);
+    let count = stones.iter().map(|&stone| count_resulting_stones(stone, blinks, &mut memo)).sum();
+    debug!(blinks, stats = %memo.stats(), "counted stones");
+    count
+}
+
+pub fn count_resulting_stones(stone: Stone, blinks: usize, memo: &mut Memo<(Stone, usize), u128>) -> Result<u128, StoneTooBig> {
+    if blinks == 0 {
+        return Ok(1);
+    }
+
+    if let Some(count) = memo.get(&(stone, blinks)) {
+        return Ok(count);
+    }
+
+    let count = if stone == 0 {
+        count_resulting_stones(1, blinks-1, memo)?
+    } else {
+        let digit_count = stone.ilog10() + 1;
+
+        if digit_count.is_multiple_of(2) {
+            let half = Stone::pow(10, digit_count/2);
+            count_resulting_stones(stone / half, blinks-1, memo)?
+                + count_resulting_stones(stone % half, blinks-1, memo)?
+        } else {
+            let Some(stone) = stone.checked_mul(2024) else {
+                return Err(StoneTooBig(stone));
+            };
+            count_resulting_stones(stone, blinks-1, memo)?
+        }
+    };
+
+    memo.insert((stone, blinks), count);
+    Ok(count)
+}
+
+pub fn parse_input(input: impl io::Read) -> Result<Vec<Stone>, Box<dyn Error>> {
+    io::read_to_string(input)?
+        .split_whitespace()
+        .map(str::parse::<Stone>)
+        .collect::<Result<_,_>>()
+        .map_err(|err| err.into())
+}
@@ -0,0 +1,154 @@
+use std::error::Error;
+use std::io;
+use geometry::neighbors;
+use runner::{AnswerKind, DayId, InputShape, Puzzle, Solution};
+
+pub struct Day12;
+impl Solution for Day12 {
+    const ID: DayId = DayId::new(2024, 12);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Garden Groups",
+        parts: &[AnswerKind::Integer, AnswerKind::Integer],
+        parameters: &[],
+        input: InputShape::Grid,
+    };
+    type Input<'a> = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        parse_input(input.as_bytes())
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Option<String> {
+        Some(solve_part_2(input).to_string())
+    }
+}
+
+pub struct PuzzleInput{
+    pub grid: Vec<Vec<usize>>,
+    pub region_count: usize,
+}
+
+pub fn solve_part_1(input: &PuzzleInput) -> usize {
+    let grid = &input.grid;
+    let mut areas = vec![0; input.region_count];
+    let mut perimeters = vec![0; input.region_count];
+
+    for (i, row) in grid.iter().enumerate() {
+        for (j, &cell) in row.iter().enumerate() {
+            areas[cell] += 1;
+
+            let perim = &mut perimeters[cell];
+            for (i2, j2) in neighbors(i, j) {
+                if i2 >= grid.len() || j2 >= grid[i2].len() || grid[i2][j2] != cell {
+                    *perim += 1;
+                }
+            }
+        }
+    }
+
+    std::iter::zip(areas, perimeters)
+        .map(|(a, p)| a * p)
+        .sum()
+}
+
+pub fn solve_part_2(input: &PuzzleInput) -> usize {
+    fn get_neighborhood(grid: &[Vec<usize>], i: usize, j: usize) -> [[usize; 3]; 3] {
+        let mut neighborhood = [[usize::MAX; 3]; 3];
+        for (ni, row) in neighborhood.iter_mut().enumerate() {
+            let i2 = (i + ni).wrapping_sub(1);
+            for (nj, val) in row.iter_mut().enumerate() {
+                let j2 = (j + nj).wrapping_sub(1);
+                if let Some(&cell) = grid::get(grid, (i2, j2)) {
+                    *val = cell;
+                }
+            }
+        }
+        neighborhood
+    }
+
+    let grid = &input.grid;
+    let mut areas = vec![0; input.region_count];
+    let mut corners = vec![0; input.region_count];
+
+    for (i, row) in grid.iter().enumerate() {
+        for (j, &cell) in row.iter().enumerate() {
+            let neighborhood = get_neighborhood(grid, i, j);
+
+            let corner_count = &mut corners[cell];
+
+            // Top left
+            let inner_corner = neighborhood[0][0] != cell && neighborhood[0][1] == cell && neighborhood[1][0] == cell;
+            let outer_corner = neighborhood[0][1] != cell && neighborhood[1][0] != cell;
+            if inner_corner || outer_corner {
+                *corner_count += 1;
+            }
+
+            // Top right
+            let inner_corner = neighborhood[0][2] != cell && neighborhood[0][1] == cell && neighborhood[1][2] == cell;
+            let outer_corner = neighborhood[0][1] != cell && neighborhood[1][2] != cell;
+            if inner_corner || outer_corner {
+                *corner_count += 1;
+            }
+
+            // Bottom left
+            let inner_corner = neighborhood[2][0] != cell && neighborhood[2][1] == cell && neighborhood[1][0] == cell;
+            let outer_corner = neighborhood[2][1] != cell && neighborhood[1][0] != cell;
+            if inner_corner || outer_corner {
+                *corner_count += 1;
+            }
+
+            // Bottom right
+            let inner_corner = neighborhood[2][2] != cell && neighborhood[2][1] == cell && neighborhood[1][2] == cell;
+            let outer_corner = neighborhood[2][1] != cell && neighborhood[1][2] != cell;
+            if inner_corner || outer_corner {
+                *corner_count += 1;
+            }
+
+            areas[cell] += 1;
+        }
+    }
+
+    std::iter::zip(areas, corners)
+        .map(|(a, c)| a * c)
+        .sum()
+}
+
+pub fn parse_input(input: impl io::Read) -> Result<PuzzleInput, Box<dyn Error>> {
+    fn flood_fill(out: &mut Vec<Vec<usize>>, val: usize, original: &[&[u8]], i: usize, j: usize) -> bool {
+        if out[i][j] != usize::MAX {
+            return false;
+        }
+        out[i][j] = val;
+
+        let current_cell = original[i][j];
+    
+        for (i2, j2) in neighbors(i, j) {
+            if i2 < original.len() && j2 < original[i2].len() && original[i2][j2] == current_cell {
+                flood_fill(out, val, original, i2, j2);
+            }
+        }
+        true
+    }
+
+    let input: String = io::read_to_string(input)?;
+    let input = grid::rows(&input);
+
+    let mut grid: Vec<Vec<_>> = input.iter()
+        .map(|row| vec![usize::MAX; row.len()])
+        .collect();
+    let mut regions = 0;
+
+    for i in 0 .. grid.len() {
+        for j in 0 .. grid[i].len() {
+            if flood_fill(&mut grid, regions, &input, i, j) {
+                regions += 1;
+            }
+        }
+    }
+
+    Ok(PuzzleInput{grid, region_count: regions})
+}
@@ -0,0 +1,136 @@
+use std::io;
+use std::error::Error;
+use regex::Regex;
+use runner::{AnswerKind, DayId, InputShape, Puzzle, Solution};
+use tracing::debug;
+
+pub struct Day13;
+impl Solution for Day13 {
+    const ID: DayId = DayId::new(2024, 13);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Claw Contraption",
+        parts: &[AnswerKind::Integer, AnswerKind::Integer],
+        parameters: &[],
+        input: InputShape::Blocks,
+    };
+    type Input<'a> = Vec<ClawMachine>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        parse_input(input.as_bytes())
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Option<String> {
+        Some(solve_part_2(input).to_string())
+    }
+}
+
+pub fn solve_part_1(machines: &[ClawMachine]) -> i128 {
+    machines.iter()
+        .filter_map(tokens_to_win)
+        .sum()
+}
+
+pub fn solve_part_2(machines: &[ClawMachine]) -> i128 {
+    let p = 10_000_000_000_000;
+    machines.iter()
+        .filter_map(|machine| tokens_to_win_prize(machine, machine.adjusted_prize_location(p, p)))
+        .sum()
+}
+
+pub fn tokens_to_win(machine: &ClawMachine) -> Option<i128> {
+    tokens_to_win_prize(machine, wide(machine.prize))
+}
+
+/// The products of coordinates overflow an `i64` once the prize is far
+/// enough away, so they are computed in `i128`, as is the prize itself.
+fn tokens_to_win_prize(machine: &ClawMachine, (p_x, p_y): (i128, i128)) -> Option<i128> {
+    let (a_x, a_y) = wide(machine.button_a);
+    let (b_x, b_y) = wide(machine.button_b);
+
+    // Solve for a and b
+    // p_x = (a * a_x) + (b * b_x)
+    // p_y = (a * a_y) + (b * b_y)
+    let det = (a_x * b_y) - (a_y * b_x);
+    let a = (p_x * b_y) + (p_y * -b_x);
+    let b = (p_x * -a_y) + (p_y * a_x);
+    if det == 0 || a % det != 0 || b % det != 0 {
+        debug!(?machine, "machine cannot be won");
+        return None;
+    }
+
+    let a = a / det;
+    let b = b / det;
+    debug!(?machine, a, b, "machine can be won");
+    Some(3*a + b)
+}
+
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ClawMachine {
+    pub button_a: (i64, i64),
+    pub button_b: (i64, i64),
+    pub prize: (i64, i64),
+}
+
+impl ClawMachine {
+    /// Where the prize is once it has been moved by `(dx, dy)`, which may be
+    /// beyond an `i64`.
+    fn adjusted_prize_location(&self, dx: i64, dy: i64) -> (i128, i128) {
+        let (px, py) = wide(self.prize);
+        (px + i128::from(dx), py + i128::from(dy))
+    }
+}
+
+fn wide((x, y): (i64, i64)) -> (i128, i128) {
+    (i128::from(x), i128::from(y))
+}
+
+pub fn parse_input(input: impl io::BufRead) -> Result<Vec<ClawMachine>, Box<dyn Error>> {
+    let lines = input.lines().collect::<Result<Vec<_>, _>>()?;
+
+    let button_a_rgx = Regex::new(r"^Button A: X\+(\d+), Y\+(\d+)$").unwrap();
+    let button_b_rgx = Regex::new(r"^Button B: X\+(\d+), Y\+(\d+)$").unwrap();
+    let prize_rgx = Regex::new(r"^Prize: X=(\d+), Y=(\d+)$").unwrap();
+
+    let mut machines = vec![];
+    for lines in lines.chunks(4) {
+        let [line1, line2, line3, ..] = lines else {
+            return Err("Invalid input".into());
+        };
+        if let Some(line4) = lines.get(3) {
+            if !line4.trim().is_empty() {
+                return Err("Invalid input".into());
+            }
+        }
+        let mut machine = ClawMachine::default();
+
+        if let Some(capture) = button_a_rgx.captures(line1.trim()) {
+            let (_, [x, y]) = capture.extract();
+            machine.button_a = (x.parse()?, y.parse()?);
+        } else {
+            return Err("Invalid input".into());
+        }
+
+        if let Some(capture) = button_b_rgx.captures(line2.trim()) {
+            let (_, [x, y]) = capture.extract();
+            machine.button_b = (x.parse()?, y.parse()?);
+        } else {
+            return Err("Invalid input".into());
+        }
+
+        if let Some(capture) = prize_rgx.captures(line3.trim()) {
+            let (_, [x, y]) = capture.extract();
+            machine.prize = (x.parse()?, y.parse()?);
+        } else {
+            return Err("Invalid input".into());
+        }
+
+        machines.push(machine);
+    }
+
+    Ok(machines)
+}
@@ -0,0 +1,162 @@
+use std::io;
+use std::error::Error;
+use regex::Regex;
+use grid::SparseGrid;
+use runner::{AnswerKind, DayId, InputShape, Parameter, Puzzle, Solution, TimedOut};
+use tracing::{debug, trace};
+
+pub struct Day14;
+impl Solution for Day14 {
+    const ID: DayId = DayId::new(2024, 14);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Restroom Redoubt",
+        parts: &[AnswerKind::Integer, AnswerKind::Integer],
+        parameters: &[
+            Parameter{name: "width", value: WIDTH as u64, description: "Width of the space the robots move in"},
+            Parameter{name: "height", value: HEIGHT as u64, description: "Height of the space the robots move in"},
+            Parameter{name: "seconds", value: SECONDS, description: "Seconds after which the robots are counted in part 1"},
+            Parameter{name: "tree_size", value: CHRISTMAS_TREE_MIN_SIZE as u64, description: "Fewest touching robots that are taken to be a Christmas tree"},
+        ],
+        input: InputShape::Lines,
+    };
+    type Input<'a> = Vec<Robot>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        parse_input(input.as_bytes())
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Option<String> {
+        Some(match solve_part_2(input) {
+            Ok(Some(t)) => t.to_string(),
+            Ok(None) => runner::fail("No Christmas tree found"),
+            Err(err) => runner::fail(err),
+        })
+    }
+}
+
+const WIDTH: i64 = 101;
+const HEIGHT: i64 = 103;
+const CHRISTMAS_TREE_MIN_SIZE: usize = 100;
+const SECONDS: u64 = 100;
+
+pub fn solve_part_1(robots: &[Robot]) -> usize {
+    let mut quadrants = [0, 0, 0, 0];
+    for robot in robots {
+        let (x, y) = robot.position_at(SECONDS);
+
+        let is_left = x < WIDTH/2;
+        let is_right = x > WIDTH/2;
+        let is_top = y < HEIGHT/2;
+        let is_bottom = y > HEIGHT/2;
+
+        if is_top && is_left {
+            quadrants[0] += 1;
+        }
+        if is_top && is_right {
+            quadrants[1] += 1;
+        }
+        if is_bottom && is_left {
+            quadrants[2] += 1;
+        }
+        if is_bottom && is_right {
+            quadrants[3] += 1;
+        }
+    }
+
+    quadrants.iter().product()
+}
+
+/// Finds the first time at which the robots form a Christmas tree, or `None`
+/// if they never do.
+pub fn solve_part_2(robots: &[Robot]) -> Result<Option<usize>, TimedOut> {
+    // Each robot's position repeats, with a period dividing
+    // lcm(WIDTH, HEIGHT), so all of them together repeat with the lcm of
+    // those periods, and a tree appears within it if at all.
+    let mut period = 1;
+    for robot in robots {
+        runner::cancel::check()?;
+        let cycle = cycle::brent(robot.position_at(0), |&pos| Some(robot.step(pos)));
+        period = lcm(period, cycle.map_or(1, |cycle| cycle.len));
+    }
+    debug!(period, "the robots' positions repeat");
+
+    let mut locations = SparseGrid::new();
+    for t in 1..=period {
+        runner::cancel::check()?;
+        runner::progress::report(t as u64, period as u64);
+        locations.clear();
+        for robot in robots {
+            let (x, y) = robot.position_at(t as u64);
+            locations.insert((y, x), ());
+        }
+
+        let largest = locations.components().iter().map(Vec::len).max().unwrap_or(0);
+        if largest >= CHRISTMAS_TREE_MIN_SIZE {
+            debug!(t, size = largest, "found a large cluster of robots:\n{}", locations.render(|_| '#'));
+            return Ok(Some(t));
+        }
+        trace!(t, largest, "no large cluster of robots");
+    }
+    Ok(None)
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    fn gcd(a: usize, b: usize) -> usize {
+        if b == 0 {a} else {gcd(b, a % b)}
+    }
+    a / gcd(a, b) * b
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Robot {
+    pub pos: (i64, i64),
+    pub vel: (i64, i64),
+}
+
+impl Robot {
+    /// Where the robot is after `t` seconds. Everything is reduced modulo the
+    /// size of the room first, so this can't overflow however far the robot
+    /// starts or however fast it moves.
+    fn position_at(&self, t: u64) -> (i64, i64) {
+        let x = self.pos.0.rem_euclid(WIDTH) + (t % WIDTH as u64) as i64 * self.vel.0.rem_euclid(WIDTH);
+        let y = self.pos.1.rem_euclid(HEIGHT) + (t % HEIGHT as u64) as i64 * self.vel.1.rem_euclid(HEIGHT);
+        (x.rem_euclid(WIDTH), y.rem_euclid(HEIGHT))
+    }
+
+    /// Where the robot is a second after being at `(x, y)`.
+    fn step(&self, (x, y): (i64, i64)) -> (i64, i64) {
+        ((x + self.vel.0.rem_euclid(WIDTH)) % WIDTH, (y + self.vel.1.rem_euclid(HEIGHT)) % HEIGHT)
+    }
+}
+
+pub fn parse_input(input: impl io::BufRead) -> Result<Vec<Robot>, Box<dyn Error>> {
+    let rgx = Regex::new(r"^p=(-?\d+),(-?\d+) v=(-?\d+),(-?\d+)").unwrap();
+
+    let mut robots = vec![];
+    for line in input.lines() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let Some(capture) = rgx.captures(line) else {
+            return Err("Invalid input".into());
+        };
+
+        let (_, [px, py, vx, vy]) = capture.extract();
+        let px = px.parse()?;
+        let py = py.parse()?;
+        let vx = vx.parse()?;
+        let vy = vy.parse()?;
+
+        robots.push(Robot{
+            pos: (px, py),
+            vel: (vx, vy)
+        });
+    }
+    Ok(robots)
+}
@@ -0,0 +1,255 @@
+use std::io;
+use std::error::Error;
+use runner::{AnswerKind, DayId, InputShape, Puzzle, Solution};
+
+pub struct Day15;
+impl Solution for Day15 {
+    const ID: DayId = DayId::new(2024, 15);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Warehouse Woes",
+        parts: &[AnswerKind::Integer, AnswerKind::Integer],
+        parameters: &[],
+        input: InputShape::Sections(&[InputShape::Grid, InputShape::Items{separator: ""}]),
+    };
+    type Input<'a> = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        parse_input(input.as_bytes())
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Option<String> {
+        Some(solve_part_2(input).to_string())
+    }
+}
+
+#[derive(Debug)]
+pub struct PuzzleInput {
+    pub grid: Vec<Vec<u8>>,
+    pub robot: (i32, i32),
+    pub moves: Vec<(i32, i32)>,
+}
+
+impl PuzzleInput {
+    /// Checks that the robot can't leave the warehouse: the grid is a
+    /// rectangle surrounded by walls, with a single robot where `robot` says,
+    /// and every move is one step in one of the four directions.
+    pub fn validate(&self) -> Result<(), Box<dyn Error>> {
+        let width = self.grid.first().map_or(0, Vec::len);
+        if self.grid.iter().any(|row| row.len() != width) {
+            return Err("Rows of the warehouse have different lengths".into());
+        }
+        for row in self.grid.iter() {
+            if let Some(tile) = row.iter().find(|tile| !b"#.O@".contains(tile)) {
+                return Err(format!("Unknown tile '{}' in the warehouse", tile.escape_ascii()).into());
+            }
+        }
+
+        let is_border = |i: usize, j: usize| i == 0 || j == 0 || i == self.grid.len() - 1 || j == width - 1;
+        let is_walled = self.grid.iter().enumerate()
+            .all(|(i, row)| row.iter().enumerate().all(|(j, tile)| !is_border(i, j) || *tile == b'#'));
+        if !is_walled {
+            return Err("The warehouse must be surrounded by walls".into());
+        }
+
+        match self.grid.iter().flatten().filter(|tile| **tile == b'@').count() {
+            0 => return Err("No robot location found in input".into()),
+            1 => {},
+            _ => return Err("Multiple robot locations found in input".into()),
+        }
+        let (i, j) = self.robot;
+        let at_robot = usize::try_from(i).ok().zip(usize::try_from(j).ok())
+            .and_then(|(i, j)| self.grid.get(i)?.get(j));
+        if at_robot != Some(&b'@') {
+            return Err(format!("The robot is not at {:?}", self.robot).into());
+        }
+
+        if let Some(d) = self.moves.iter().find(|d| !matches!(d, (-1 | 1, 0) | (0, -1 | 1))) {
+            return Err(format!("Invalid move {d:?}").into());
+        }
+        Ok(())
+    }
+}
+
+pub fn solve_part_1(input: &PuzzleInput) -> usize {
+    let mut grid = input.grid.clone();
+    let mut robot = input.robot;
+
+    for (d0, d1) in input.moves.iter() {
+        let p1 = (robot.0 + d0, robot.1 + d1);
+        let mut p2 = p1;
+
+        while grid[p2.0 as usize][p2.1 as usize] == b'O' {
+            p2.0 += d0;
+            p2.1 += d1;
+        }
+
+        if grid[p2.0 as usize][p2.1 as usize] != b'#' {
+            grid[p2.0 as usize][p2.1 as usize] = b'O';
+            grid[p1.0 as usize][p1.1 as usize] = b'@';
+            grid[robot.0 as usize][robot.1 as usize] = b'.';
+            robot = p1;
+        }
+    }
+    
+    gps_sum(&grid, b'O')
+}
+
+pub fn solve_part_2(input: &PuzzleInput) -> usize {
+    let mut warehouse = WideWarehouse::new(input);
+    for &d in input.moves.iter() {
+        warehouse.move_robot(d);
+    }
+
+    gps_sum(&warehouse.grid, b'[')
+}
+
+/// The warehouse of part 2, where everything except the robot is twice as
+/// wide.
+#[derive(Debug, Clone)]
+pub struct WideWarehouse {
+    pub grid: Vec<Vec<u8>>,
+    pub robot: (i32, i32),
+}
+
+impl WideWarehouse {
+    pub fn new(input: &PuzzleInput) -> Self {
+        let grid = widen_grid(&input.grid);
+        let robot = (input.robot.0, input.robot.1 * 2);
+        Self{grid, robot}
+    }
+
+    /// Tries to move the robot one step, pushing any boxes in the way.
+    pub fn move_robot(&mut self, (d0, d1): (i32, i32)) {
+        let grid = &mut self.grid;
+        let robot = self.robot;
+        let destination = (robot.0 + d0, robot.1 + d1);
+
+        match (d0, d1) {
+            (d0, 0) => { // Vertical move
+                let mut next = grid.clone();
+                if push_vertically(&mut next, destination, d0) {
+                    next[destination.0 as usize][destination.1 as usize] = b'@';
+                    next[robot.0 as usize][robot.1 as usize] = b'.';
+                    self.robot = destination;
+                    *grid = next;
+                }
+            },
+            (0, d1) => { // Horizontal move
+                let row = &mut grid[robot.0 as usize];
+                let mut j = destination.1;
+                while b"[]".contains(&row[j as usize]) {
+                    j += d1;
+                }
+                if row[j as usize] == b'.' {
+                    while j != destination.1 {
+                        row[j as usize] = row[(j-d1) as usize];
+                        j -= d1;
+                    }
+                    row[destination.1 as usize] = b'@';
+                    row[robot.1 as usize] = b'.';
+                    self.robot = destination;
+                }
+            },
+            // Not one of the four directions, so the robot stays put.
+            _ => {}
+        }
+    }
+}
+
+pub fn gps_sum(grid: &[Vec<u8>], val: u8) -> usize {
+    grid.iter()
+        .enumerate()
+        .flat_map(|(i, row)| row.iter().enumerate().map(move |(j, tile)| (i, j, tile)))
+        .filter(|(_, _, tile)| **tile == val)
+        .map(|(i, j, _)| i*100 + j)
+        .sum()
+}
+
+fn push_vertically(grid: &mut [Vec<u8>], pos: (i32, i32), d: i32) -> bool {
+    let (i, j) = match grid[pos.0 as usize][pos.1 as usize] {
+        b'[' => pos,
+        b']' => (pos.0, pos.1 - 1),
+        b'.' => return true,
+        // Walls, and anything else that isn't a box, stay where they are.
+        _ => return false,
+    };
+
+    let front_left = (i+d, j);
+    let front_right = (i+d, j+1);
+    if push_vertically(grid, front_left, d) && push_vertically(grid, front_right, d) {
+        grid[i as usize][j as usize] = b'.';
+        grid[i as usize][(j+1) as usize] = b'.';
+        grid[front_left.0 as usize][front_left.1 as usize] = b'[';
+        grid[front_right.0 as usize][front_right.1 as usize] = b']';
+        true
+    } else {
+        false
+    }
+}
+
+fn widen_grid(grid: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    fn widen_row(row: &[u8]) -> Vec<u8> {
+        row.iter()
+            .flat_map(|tile| match tile {
+                b'@' => *b"@.",
+                b'#' => *b"##",
+                b'O' => *b"[]",
+                b'.' => *b"..",
+                _ => *b"..",
+            })
+            .collect()
+    }
+
+    grid.iter()
+        .map(|row| widen_row(row))
+        .collect()
+}
+
+pub fn parse_input(input: impl io::BufRead) -> Result<PuzzleInput, Box<dyn Error>> {
+    let mut grid = vec![];
+    let mut moves = vec![];
+    let mut robot = None;
+
+    let mut is_first_section = true;
+    for (line_num, line) in input.lines().enumerate() {
+        let line = line?;
+
+        if line.trim().is_empty() {
+            is_first_section = false;
+            continue;
+        }
+
+        if is_first_section {
+            grid.push(Vec::from(line.as_bytes()));
+            let row = grid.last_mut().unwrap();
+            for (j, tile) in row.iter().enumerate() {
+                if *tile == b'@' {
+                    if robot.is_some() {
+                        return Err("Multiple robot locations found in input".into());
+                    }
+                    robot = Some((line_num as i32, j as i32));
+                }
+            }
+        } else {
+            let parsed = line.chars().filter_map(|c| match c {
+                '^' => Some((-1, 0)),
+                'v' => Some((1, 0)),
+                '<' => Some((0, -1)),
+                '>' => Some((0, 1)),
+                _ => None,
+            });
+            moves.extend(parsed);
+        }
+    }
+
+    let Some(robot) = robot else {
+        return Err("No robot location found in input".into());
+    };
+    let input = PuzzleInput{grid, robot, moves};
+    input.validate()?;
+    Ok(input)
+}
@@ -0,0 +1,125 @@
+use std::io;
+use std::error::Error;
+use std::collections::BinaryHeap;
+use std::cmp::{PartialOrd, Ord, Ordering};
+use grid::CellSet;
+use runner::{AnswerKind, DayId, InputShape, Puzzle, Solution};
+
+pub struct Day16;
+impl Solution for Day16 {
+    const ID: DayId = DayId::new(2024, 16);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Reindeer Maze",
+        parts: &[AnswerKind::Integer],
+        parameters: &[],
+        input: InputShape::Grid,
+    };
+    type Input<'a> = PuzzleInput<&'a [u8]>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        from_rows(grid::rows(input))
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        solve_part_1(input).map_or_else(|| runner::fail("No answer found"), |score| score.to_string())
+    }
+}
+
+/// The maze, whose rows are owned by default, but can be borrowed from the
+/// input instead.
+pub struct PuzzleInput<Row = Vec<u8>> {
+    pub maze: Vec<Row>,
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchState {
+    pos: (usize, usize),
+    dir: (usize, usize),
+    score: usize,
+}
+
+impl Ord for SearchState {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score.cmp(&other.score).reverse()
+    }
+}
+impl PartialOrd for SearchState {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Finds the lowest score of any path from the start to the end, or `None` if
+/// the end can't be reached. Anything outside the maze counts as a wall.
+pub fn solve_part_1(input: &PuzzleInput<impl AsRef<[u8]>>) -> Option<usize> {
+    let maze = &input.maze;
+    let start = input.start;
+    let end = input.end;
+
+    // One layer per direction, in the order of `direction_index`.
+    let mut visited = CellSet::covering_with_layers(maze, 4);
+    let mut heap = BinaryHeap::new();
+    heap.push(SearchState{
+        pos: start,
+        dir: (0, 1),
+        score: 0,
+    });
+
+    while let Some(current) = heap.pop() {
+        let (i, j) = current.pos;
+        // States come off the heap in order of score, so the first one at the
+        // end has the lowest.
+        if current.pos == end {
+            return Some(current.score);
+        }
+        if grid::get(maze, (i, j)).is_none_or(|&tile| tile == b'#') || !visited.insert_in(direction_index(current.dir), current.pos) {
+            continue;
+        }
+        
+        let edges = match current.dir {
+            (0, _) => [(current.dir, 1), ((1, 0), 1001), ((usize::MAX, 0), 1001)],
+            (_, 0) => [(current.dir, 1), ((0, 1), 1001), ((0, usize::MAX), 1001)],
+            _ => unreachable!()
+        };
+
+        for ((di, dj), cost) in edges {
+            let next_i = i.overflowing_add(di).0;
+            let next_j = j.overflowing_add(dj).0;
+            heap.push(SearchState{
+                score: cost + current.score,
+                pos: (next_i, next_j),
+                dir: (di, dj),
+            });
+        }
+    }
+
+    None
+}
+
+/// Numbers the four directions that a state can face, where `usize::MAX`
+/// stands for -1.
+fn direction_index(dir: (usize, usize)) -> usize {
+    match dir {
+        (0, 1) => 0,
+        (1, 0) => 1,
+        (0, _) => 2,
+        _ => 3,
+    }
+}
+
+pub fn parse_input(input: impl io::Read) -> Result<PuzzleInput, Box<dyn Error>> {
+    let raw = io::read_to_string(input)?;
+    from_rows(grid::rows_owned(&raw))
+}
+
+fn from_rows<Row: AsRef<[u8]>>(maze: Vec<Row>) -> Result<PuzzleInput<Row>, Box<dyn Error>> {
+    let Some(start) = grid::find(&maze, &b'S') else {
+        return Err("No start location".into());
+    };
+    let Some(end) = grid::find(&maze, &b'E') else {
+        return Err("No end location".into());
+    };
+    Ok(PuzzleInput{maze, start, end})
+}
@@ -0,0 +1,202 @@
+use std::fmt;
+use std::io;
+use std::error::Error;
+use runner::{AnswerKind, DayId, InputShape, Puzzle, Solution};
+
+pub struct Day17;
+impl Solution for Day17 {
+    const ID: DayId = DayId::new(2024, 17);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Chronospatial Computer",
+        parts: &[AnswerKind::Text],
+        parameters: &[],
+        input: InputShape::Sections(&[InputShape::Lines, InputShape::Lines]),
+    };
+    type Input<'a> = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        parse_input(input.as_bytes())
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        solve_part_1(input).unwrap_or_else(runner::fail)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PuzzleInput {
+    pub register_a: u64,
+    pub register_b: u64,
+    pub register_c: u64,
+    pub program: Vec<u8>,
+}
+
+/// Runs the program, which may never halt.
+pub fn solve_part_1(input: &PuzzleInput) -> Result<String, Box<dyn Error>> {
+    let mut computer = Computer::new(input);
+    while computer.step(&input.program)? {
+        runner::cancel::check()?;
+    }
+    Ok(computer.out)
+}
+
+/// An instruction that the computer can't execute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidInstruction {
+    /// The opcode at `ip` is the last number of the program, so it has no
+    /// operand.
+    MissingOperand{ip: usize},
+    /// The opcode at `ip` isn't one of the eight instructions.
+    UnknownOpcode{ip: usize, opcode: u8},
+    /// The instruction at `ip` takes a combo operand, but its operand isn't
+    /// one. Combo operand 7 is reserved.
+    InvalidComboOperand{ip: usize, operand: u8},
+}
+
+impl fmt::Display for InvalidInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::MissingOperand{ip} => write!(f, "The instruction at {ip} has no operand"),
+            Self::UnknownOpcode{ip, opcode} => write!(f, "Unknown opcode {opcode} at {ip}"),
+            Self::InvalidComboOperand{ip, operand} => write!(f, "Invalid combo operand {operand} at {ip}"),
+        }
+    }
+}
+
+impl Error for InvalidInstruction {}
+
+/// Decodes the instruction at `ip` into its opcode and operand.
+fn decode(program: &[u8], ip: usize) -> Result<(u8, u8), InvalidInstruction> {
+    let opcode = program[ip];
+    let Some(&operand) = program.get(ip + 1) else {
+        return Err(InvalidInstruction::MissingOperand{ip});
+    };
+    match opcode {
+        0 | 2 | 5..=7 if operand > 6 => Err(InvalidInstruction::InvalidComboOperand{ip, operand}),
+        0..=7 => Ok((opcode, operand)),
+        _ => Err(InvalidInstruction::UnknownOpcode{ip, opcode}),
+    }
+}
+
+/// Checks every instruction that the program could reach, following both
+/// ways out of each jump, so that running it can't fail.
+pub fn validate(program: &[u8]) -> Result<(), InvalidInstruction> {
+    let mut seen = vec![false; program.len()];
+    let mut pending = vec![0];
+    while let Some(ip) = pending.pop() {
+        if ip >= program.len() || seen[ip] {
+            continue;
+        }
+        seen[ip] = true;
+        let (opcode, operand) = decode(program, ip)?;
+        pending.push(ip + 2);
+        if opcode == 3 {
+            pending.push(operand as usize);
+        }
+    }
+    Ok(())
+}
+
+/// `value` divided by 2 to the power of `exponent`. Shifting by 64 or more
+/// leaves nothing, rather than overflowing.
+fn divide(value: u64, exponent: u64) -> u64 {
+    u32::try_from(exponent).ok()
+        .and_then(|exponent| value.checked_shr(exponent))
+        .unwrap_or(0)
+}
+
+/// The state of the computer between two instructions.
+#[derive(Debug, Clone)]
+pub struct Computer {
+    pub register_a: u64,
+    pub register_b: u64,
+    pub register_c: u64,
+    pub ip: usize,
+    pub out: String,
+}
+
+impl Computer {
+    pub fn new(input: &PuzzleInput) -> Self {
+        Self{
+            register_a: input.register_a,
+            register_b: input.register_b,
+            register_c: input.register_c,
+            ip: 0,
+            out: String::new(),
+        }
+    }
+
+    /// Executes the instruction at `ip`. Returns false if the program has
+    /// already halted.
+    pub fn step(&mut self, program: &[u8]) -> Result<bool, InvalidInstruction> {
+        if self.ip >= program.len() {
+            return Ok(false);
+        }
+        let (opcode, operand) = decode(program, self.ip)?;
+        let literal_operand = u64::from(operand);
+        let combo_operand = match operand {
+            0..=3 => literal_operand,
+            4 => self.register_a,
+            5 => self.register_b,
+            _ => self.register_c,
+        };
+
+        match opcode {
+            0 => self.register_a = divide(self.register_a, combo_operand),
+            1 => self.register_b ^= literal_operand,
+            2 => self.register_b = combo_operand % 8,
+            3 => if self.register_a != 0 {
+                self.ip = operand as usize;
+                return Ok(true);
+            }
+            4 => self.register_b ^= self.register_c,
+            5 => {
+                if !self.out.is_empty() {
+                    self.out.push(',');
+                }
+                self.out.push_str(&(combo_operand % 8).to_string());
+            }
+            6 => self.register_b = divide(self.register_a, combo_operand),
+            _ => self.register_c = divide(self.register_a, combo_operand),
+        }
+
+        self.ip += 2;
+        Ok(true)
+    }
+}
+
+pub fn parse_input(input: impl io::BufRead) -> Result<PuzzleInput, Box<dyn Error>> {
+    let mut lines = input.lines();
+    
+    let line = lines.next().ok_or("Unexpected end of file")??;
+    let Some(("Register A", a)) = line.split_once(':') else {
+        return Err("Invalid input")?;
+    };
+    let register_a = a.trim().parse()?;
+
+    let line = lines.next().ok_or("Unexpected end of file")??;
+    let Some(("Register B", b)) = line.split_once(':') else {
+        return Err("Invalid input")?;
+    };
+    let register_b = b.trim().parse()?;
+
+    let line = lines.next().ok_or("Unexpected end of file")??;
+    let Some(("Register C", c)) = line.split_once(':') else {
+        return Err("Invalid input")?;
+    };
+    let register_c = c.trim().parse()?;
+
+    let line = lines.nth(1).ok_or("Unexpected end of file")??;
+    let Some(("Program", program)) = line.split_once(':') else {
+        return Err("Invalid input")?;
+    };
+    let program: Vec<u8> = program.split(',')
+        .map(|s| s.trim().parse())
+        .collect::<Result<_,_>>()?;
+    if program.iter().any(|&n| n > 7) {
+        return Err("Program values must be 3-bit numbers".into());
+    }
+    validate(&program)?;
+
+    Ok(PuzzleInput{register_a, register_b, register_c, program})
+}
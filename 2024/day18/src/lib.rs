@@ -0,0 +1,196 @@
+use std::io;
+use std::error::Error;
+use std::collections::VecDeque;
+use geometry::neighbors;
+use runner::{AnswerKind, DayId, InputShape, Parameter, Puzzle, Solution, Variant};
+use tracing::{debug, trace};
+
+pub struct Day18;
+impl Solution for Day18 {
+    const ID: DayId = DayId::new(2024, 18);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "RAM Run",
+        parts: &[AnswerKind::Integer, AnswerKind::Text],
+        parameters: &[
+            Parameter{name: "size", value: (MAX_X+1) as u64, description: "Width and height of the memory space"},
+            Parameter{name: "bytes", value: BYTES as u64, description: "Bytes that have fallen in part 1"},
+        ],
+        input: InputShape::Lines,
+    };
+    type Input<'a> = Vec<(usize, usize)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        parse_bytes(input.as_bytes())
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        solve_part_1(input).map_or_else(|| runner::fail("No answer found"), |steps| steps.to_string())
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Option<String> {
+        Some(solve_part_2(input).to_string())
+    }
+
+    fn variants() -> Vec<Variant<Self>> {
+        vec![
+            Variant{name: "union-find", part: 2, solve: |input| solve_part_2_union_find(input)},
+        ]
+    }
+}
+
+const MAX_X: usize = 70;
+const MAX_Y: usize = 70;
+const BYTES: usize = 1024;
+
+type Map = [[usize; MAX_Y+1]; MAX_X+1];
+
+/// Finds the fewest steps to the exit after the first `BYTES` bytes have
+/// fallen, or `None` if they cut it off.
+pub fn solve_part_1(coords: &[(usize, usize)]) -> Option<u64> {
+    bfs(&corrupt(coords), BYTES)
+}
+
+pub fn solve_part_2(coords: &[(usize, usize)]) -> String {
+    let map = corrupt(coords);
+    
+    let mut lo = 0;
+    let mut hi = coords.len();
+    let searches = u64::from(usize::BITS - coords.len().leading_zeros());
+    let mut done = 0;
+    while lo < hi {
+        runner::progress::report(done, searches);
+        done += 1;
+        let mid = lo + (hi - lo) / 2;
+        let reachable = bfs(&map, mid+1).is_some();
+        trace!(bytes = mid+1, reachable, "checked whether the exit is reachable");
+        if reachable {
+            lo = mid+1;
+        } else {
+            hi = mid;
+        }
+    }
+
+    if let Some((x, y)) = coords.get(lo) {
+        debug!(byte = lo, x, y, "found the first byte that cuts off the exit");
+        format!("{x},{y}")
+    } else {
+        runner::fail("No answer found")
+    }
+}
+
+/// Like [`solve_part_2`], but starts with every byte fallen and takes them
+/// away again from the last one, joining each freed cell to the regions of
+/// free cells around it, until the exit is in the same region as the start.
+pub fn solve_part_2_union_find(coords: &[(usize, usize)]) -> String {
+    fn find(parent: &mut [usize], mut cell: usize) -> usize {
+        while parent[cell] != cell {
+            parent[cell] = parent[parent[cell]];
+            cell = parent[cell];
+        }
+        cell
+    }
+
+    fn union(parent: &mut [usize], a: usize, b: usize) {
+        let (a, b) = (find(parent, a), find(parent, b));
+        parent[a] = b;
+    }
+
+    let map = corrupt(coords);
+    // The start is free even if a byte falls on it, like in `bfs`.
+    let is_free = |(x, y): (usize, usize), fallen: usize| (x, y) == (0, 0) || map[x][y] >= fallen;
+    let index = |(x, y): (usize, usize)| x * (MAX_Y+1) + y;
+    let join_neighbors = |parent: &mut [usize], (x, y): (usize, usize), fallen: usize| {
+        for (nx, ny) in neighbors(x, y) {
+            if nx <= MAX_X && ny <= MAX_Y && is_free((nx, ny), fallen) {
+                union(parent, index((x, y)), index((nx, ny)));
+            }
+        }
+    };
+
+    let mut parent: Vec<usize> = (0..(MAX_X+1) * (MAX_Y+1)).collect();
+    for x in 0..=MAX_X {
+        for y in 0..=MAX_Y {
+            if is_free((x, y), coords.len()) {
+                join_neighbors(&mut parent, (x, y), coords.len());
+            }
+        }
+    }
+
+    let exit = index((MAX_X, MAX_Y));
+    if find(&mut parent, 0) == find(&mut parent, exit) {
+        return runner::fail("No answer found");
+    }
+    for (i, &(x, y)) in coords.iter().enumerate().rev() {
+        // Only the first byte to fall on a cell blocks it.
+        if map[x][y] != i || (x, y) == (0, 0) {
+            continue;
+        }
+        join_neighbors(&mut parent, (x, y), i);
+        if find(&mut parent, 0) == find(&mut parent, exit) {
+            debug!(byte = i, x, y, "found the first byte that cuts off the exit");
+            return format!("{x},{y}");
+        }
+    }
+    runner::fail("No answer found")
+}
+
+/// Maps each position to the index of the byte that falls on it first.
+/// Every byte must be inside the memory space, which parsing checks.
+fn corrupt(coords: &[(usize, usize)]) -> Map {
+    let mut map = [[usize::MAX; MAX_Y+1]; MAX_X+1];
+    for (i, &(x, y)) in coords.iter().enumerate().rev() {
+        map[x][y] = i;
+    }
+    map
+}
+
+fn bfs(map: &Map, t: usize) -> Option<u64> {
+    let mut visited = [[false; MAX_Y+1]; MAX_X+1];
+    visited[0][0] = true;
+
+    let mut queue = VecDeque::new();
+    queue.push_back((0usize, 0usize, 0));
+
+    while let Some((x, y, dist)) = queue.pop_front() {
+        for (nx, ny) in neighbors(x, y) {
+            if nx > MAX_X || ny > MAX_Y || visited[nx][ny] {
+                continue;
+            }
+            if map[nx][ny] < t {
+                continue;
+            }
+            if (nx, ny) == (MAX_X, MAX_Y) {
+                return Some(dist+1);
+            }
+            visited[nx][ny] = true;
+            queue.push_back((nx, ny, dist+1));
+        }
+    }
+
+    None
+}
+
+fn check_in_memory((x, y): (usize, usize)) -> Result<(usize, usize), Box<dyn Error>> {
+    if x > MAX_X || y > MAX_Y {
+        return Err(format!("Byte {x},{y} is outside the memory space").into());
+    }
+    Ok((x, y))
+}
+
+fn parse_bytes(input: &[u8]) -> Result<Vec<(usize, usize)>, Box<dyn Error>> {
+    parsing::fast_lines(
+        input,
+        |line| {
+            let comma = line.iter().position(|&b| b == b',').ok_or("Invalid input")?;
+            check_in_memory((parsing::int(&line[..comma])?, parsing::int(&line[comma+1..])?))
+        },
+        |line| {
+            let (x, y) = line.split_once(',').ok_or("Invalid input")?;
+            check_in_memory((x.parse()?, y.parse()?))
+        },
+    )
+}
+
+pub fn parse_input(input: impl io::Read) -> Result<Vec<(usize, usize)>, Box<dyn Error>> {
+    parse_bytes(&parsing::read_all(input)?)
+}
@@ -0,0 +1,148 @@
+use std::io;
+use std::error::Error;
+use std::str::Chars;
+use num_bigint::BigUint;
+use memo::Memo;
+use num_traits::{CheckedAdd, One, Zero};
+use runner::{AnswerKind, DayId, InputShape, Puzzle, Solution};
+use tracing::{debug, trace};
+
+mod trie;
+pub use trie::Trie;
+
+pub struct Day19;
+impl Solution for Day19 {
+    const ID: DayId = DayId::new(2024, 19);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Linen Layout",
+        parts: &[AnswerKind::Integer, AnswerKind::Integer],
+        parameters: &[],
+        input: InputShape::Sections(&[InputShape::Items{separator: ", "}, InputShape::Lines]),
+    };
+    type Input<'a> = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        parse_input(input.as_bytes())
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        solve_part_1(input).to_string()
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Option<String> {
+        Some(solve_part_2(input).to_string())
+    }
+}
+
+pub struct PuzzleInput {
+    pub trie: Trie,
+    pub designs: Vec<String>,
+}
+
+pub fn solve_part_1(input: &PuzzleInput) -> usize {
+    input.designs.iter()
+        .filter(|d| {
+            let possible = design_is_possible(d, &input.trie);
+            if !possible {
+                debug!(design = %d, "design is impossible");
+            }
+            possible
+        })
+        .count()
+}
+
+pub fn design_is_possible(design: &str, trie: &Trie) -> bool {
+    fn is_possible(mut chars: Chars, mut current: &Trie, root: &Trie) -> bool {
+        while let Some(c) = chars.next() {
+            let Some(next) = current.get(c) else {
+                return false;
+            };
+            current = next;
+    
+            if current.is_end() && is_possible(chars.clone(), root, root) {
+                return true;
+            }
+        }
+        
+        current.is_end()
+    }
+
+    is_possible(design.chars(), trie, trie)
+}
+
+
+pub fn solve_part_2(input: &PuzzleInput) -> BigUint {
+    input.designs.iter()
+        .map(|d| {
+            let arrangements = count_arrangements(d, &input.trie);
+            trace!(design = %d, %arrangements);
+            arrangements
+        })
+        .sum()
+}
+
+/// The number of arrangements grows exponentially with the length of the
+/// design, so they are counted in a `u64` if possible, and in a `BigUint` if
+/// that overflows.
+pub fn count_arrangements(design: &str, trie: &Trie) -> BigUint {
+    count_arrangements_as::<u64>(design, trie)
+        .map(BigUint::from)
+        .or_else(|| count_arrangements_as::<BigUint>(design, trie))
+        .unwrap_or_default()
+}
+
+/// Returns `None` if the count overflows `N`.
+fn count_arrangements_as<N: Clone + Zero + One + CheckedAdd>(design: &str, trie: &Trie) -> Option<N> {
+    /// The number of arrangements of `chars[i..]`.
+    fn count_from<N: Clone + Zero + One + CheckedAdd>(chars: &[char], i: usize, trie: &Trie, memo: &mut Memo<usize, Option<N>>) -> Option<N> {
+        if i == chars.len() {
+            return Some(N::one());
+        }
+
+        memo.get_or_insert_with(i, |memo| {
+            let mut count = N::zero();
+            let mut node = trie;
+            for (j, c) in chars[i..].iter().enumerate() {
+                let Some(next) = node.get(*c) else {
+                    break;
+                };
+                if next.is_end() {
+                    count = count.checked_add(&count_from(chars, i + j + 1, trie, memo)?)?;
+                }
+                node = next;
+            }
+            Some(count)
+        })
+    }
+
+    let chars: Vec<_> = design.chars().collect();
+    let mut memo = Memo::new();
+    // Filling the memo from the end keeps the recursion one level deep,
+    // however long the design is.
+    for i in (0..chars.len()).rev() {
+        count_from::<N>(&chars, i, trie, &mut memo)?;
+    }
+    let count = count_from(&chars, 0, trie, &mut memo);
+    trace!(design, stats = %memo.stats(), "counted arrangements");
+    count
+}
+
+pub fn parse_input(input: impl io::Read) -> Result<PuzzleInput, Box<dyn Error>> {
+    let full_file = io::read_to_string(input)?;
+    let mut lines = full_file.lines();
+
+    let Some(first_line) = lines.next() else {
+        return Err("Invalid input")?;
+    };
+    let trie: Trie = first_line.split(',')
+        .map(str::trim)
+        .collect();
+
+    let designs = lines
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect();
+
+    Ok(PuzzleInput{trie, designs})
+}
@@ -13,8 +13,7 @@ impl Trie {
     pub fn insert(&mut self, s: &str) {
         let mut node = self;
         for c in s.chars() {
-            node = node.children.entry(c)
-                       .or_insert_with(Self::new);
+            node = node.children.entry(c).or_default();
         }
         node.is_str_end = true;
     }
@@ -31,6 +30,24 @@ impl Trie {
         node.is_str_end
     }
 
+    /// All strings in the trie, in sorted order.
+    pub fn words(&self) -> Vec<String> {
+        let mut words = vec![];
+        let mut stack = vec![(self, String::new())];
+        while let Some((node, prefix)) = stack.pop() {
+            if node.is_str_end {
+                words.push(prefix.clone());
+            }
+            for (c, child) in node.children.iter() {
+                let mut word = prefix.clone();
+                word.push(*c);
+                stack.push((child, word));
+            }
+        }
+        words.sort();
+        words
+    }
+
     pub fn get(&self, c: char) -> Option<&Trie> {
         self.children.get(&c)
     }
@@ -40,6 +57,12 @@ impl Trie {
     }
 }
 
+impl Default for Trie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'a> FromIterator<&'a str> for Trie {
     fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
         let mut trie = Self::new();
@@ -0,0 +1,184 @@
+use std::io;
+use std::error::Error;
+use std::collections::VecDeque;
+use geometry::neighbors;
+use runner::{AnswerKind, DayId, InputShape, Parameter, Puzzle, Solution, TimedOut, Variant};
+
+pub struct Day20;
+impl Solution for Day20 {
+    const ID: DayId = DayId::new(2024, 20);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Race Condition",
+        parts: &[AnswerKind::Integer],
+        parameters: &[
+            Parameter{name: "saving", value: 100, description: "Fewest picoseconds a cheat must save to be counted"},
+        ],
+        input: InputShape::Grid,
+    };
+    type Input<'a> = PuzzleInput<&'a [u8]>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        from_rows(grid::rows(input))
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        answer(solve_part_1(input))
+    }
+
+    fn variants() -> Vec<Variant<Self>> {
+        vec![
+            Variant{
+                name: "distance-grid",
+                part: 1,
+                solve: |input| answer(solve_part_1_distance_grid(input)),
+            },
+        ]
+    }
+}
+
+fn answer(cheats: Result<Option<u64>, TimedOut>) -> String {
+    match cheats {
+        Ok(Some(cheats)) => cheats.to_string(),
+        Ok(None) => runner::fail("No answer found"),
+        Err(err) => runner::fail(err),
+    }
+}
+
+/// The map, whose rows are owned by default, but can be borrowed from the
+/// input instead.
+pub struct PuzzleInput<Row = Vec<u8>> {
+    pub map: Vec<Row>,
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+}
+
+/// Counts the cheats that save at least 100 picoseconds, or returns `None` if
+/// the end can't be reached without cheating.
+pub fn solve_part_1(input: &PuzzleInput<impl AsRef<[u8]>>) -> Result<Option<u64>, TimedOut> {
+    let PuzzleInput{map, start, end} = input;
+
+    let dists = bfs(map, *start, *end, None)?;
+    let end_dist = dists.get(*end);
+    if end_dist == u64::MAX {
+        return Ok(None);
+    }
+
+    let mut answer = 0;
+    for (i, row) in map.iter().enumerate() {
+        runner::progress::report(i as u64, map.len() as u64);
+        for (j, &tile) in row.as_ref().iter().enumerate() {
+            if tile != b'#' || dists.get((i, j)) >= end_dist {
+                continue;
+            }
+
+            let new_dist = bfs(map, *start, *end, Some((i, j)))?.get(*end);
+            if new_dist + 100 <= end_dist {
+                answer += 1;
+            }
+        }
+    }
+    Ok(Some(answer))
+}
+
+/// Like [`solve_part_1`], but finds the distances from the start and to the
+/// end once, instead of searching again for every wall. A path through a
+/// removed wall goes from the start to the wall, and then from one of the
+/// wall's neighbours to the end.
+pub fn solve_part_1_distance_grid(input: &PuzzleInput<impl AsRef<[u8]>>) -> Result<Option<u64>, TimedOut> {
+    let PuzzleInput{map, start, end} = input;
+
+    let from_start = bfs(map, *start, *end, None)?;
+    let end_dist = from_start.get(*end);
+    if end_dist == u64::MAX {
+        return Ok(None);
+    }
+    // Paths to the end that pass the start are never the shortest, so it
+    // doesn't matter that this search stops there.
+    let to_end = bfs(map, *end, *start, None)?;
+
+    let mut answer = 0;
+    for (i, row) in map.iter().enumerate() {
+        for (j, &tile) in row.as_ref().iter().enumerate() {
+            if tile != b'#' || from_start.get((i, j)) >= end_dist {
+                continue;
+            }
+            let rest = neighbors(i, j).into_iter()
+                .filter(|&next| grid::get(map, next).is_some_and(|&tile| tile != b'#'))
+                .map(|next| to_end.get(next))
+                .filter(|&dist| dist != u64::MAX)
+                .min();
+            if rest.is_some_and(|rest| from_start.get((i, j)) + 1 + rest + 100 <= end_dist) {
+                answer += 1;
+            }
+        }
+    }
+    Ok(Some(answer))
+}
+
+/// The distance to each cell of a map from some start, or `u64::MAX` for
+/// cells that weren't reached, with a row for each of the map's rows.
+struct Distances {
+    width: usize,
+    dists: Vec<u64>,
+}
+
+impl Distances {
+    /// The distance to `(i, j)`, which must be on the map.
+    fn get(&self, (i, j): (usize, usize)) -> u64 {
+        self.dists[i * self.width + j]
+    }
+}
+
+/// The distance to each cell from `start`, searching no further than `end`,
+/// as if the wall at `removed` weren't there. Part 1 searches again for
+/// every wall, so this checks the budget.
+fn bfs(maze: &[impl AsRef<[u8]>], start: (usize, usize), end: (usize, usize), removed: Option<(usize, usize)>) -> Result<Distances, TimedOut> {
+    let width = maze.iter().map(|row| row.as_ref().len()).max().unwrap_or(0);
+    let mut dists = Distances{width, dists: vec![u64::MAX; maze.len() * width]};
+    if grid::get(maze, start).is_some() {
+        dists.dists[start.0 * width + start.1] = 0;
+    }
+
+    let mut queue = VecDeque::new();
+    queue.push_back((start.0, start.1, 0));
+
+    while let Some((i, j, current_dist)) = queue.pop_front() {
+        runner::cancel::check()?;
+        for (next_i, next_j) in neighbors(i, j) {
+            let Some(&tile) = grid::get(maze, (next_i, next_j)) else {
+                continue;
+            };
+
+            let next_dist = current_dist + 1;
+            let dist = &mut dists.dists[next_i * width + next_j];
+            if next_dist >= *dist {
+                continue;
+            }
+            *dist = next_dist;
+
+            let is_wall = tile == b'#' && removed != Some((next_i, next_j));
+            if (next_i, next_j) == end || is_wall {
+                continue;
+            }
+
+            queue.push_back((next_i, next_j, next_dist));
+        }
+    }
+
+    Ok(dists)
+}
+
+pub fn parse_input(input: impl io::Read) -> Result<PuzzleInput, Box<dyn Error>> {
+    let raw = io::read_to_string(input)?;
+    from_rows(grid::rows_owned(&raw))
+}
+
+fn from_rows<Row: AsRef<[u8]>>(map: Vec<Row>) -> Result<PuzzleInput<Row>, Box<dyn Error>> {
+    let Some(start) = grid::find(&map, &b'S') else {
+        return Err("No start location".into());
+    };
+    let Some(end) = grid::find(&map, &b'E') else {
+        return Err("No end location".into());
+    };
+    Ok(PuzzleInput{map, start, end})
+}
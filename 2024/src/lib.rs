@@ -0,0 +1,34 @@
+//! Registry of the 2024 solutions. Each day's crate is re-exported, so that
+//! other crates can call its solver functions directly.
+
+use runner::Day;
+
+pub use {
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10,
+    day11, day12, day13, day14, day15, day16, day17, day18, day19, day20,
+};
+
+pub fn days() -> Vec<Day> {
+    vec![
+        Day::of::<day01::Day01>(),
+        Day::of::<day02::Day02>(),
+        Day::of::<day03::Day03>(),
+        Day::of::<day04::Day04>(),
+        Day::of::<day05::Day05>(),
+        Day::of::<day06::Day06>(),
+        Day::of::<day07::Day07>(),
+        Day::of::<day08::Day08>(),
+        Day::of::<day09::Day09>(),
+        Day::of::<day10::Day10>(),
+        Day::of::<day11::Day11>(),
+        Day::of::<day12::Day12>(),
+        Day::of::<day13::Day13>(),
+        Day::of::<day14::Day14>(),
+        Day::of::<day15::Day15>(),
+        Day::of::<day16::Day16>(),
+        Day::of::<day17::Day17>(),
+        Day::of::<day18::Day18>(),
+        Day::of::<day19::Day19>(),
+        Day::of::<day20::Day20>(),
+    ]
+}
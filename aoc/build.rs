@@ -0,0 +1,56 @@
+//! With the `embed-inputs` feature, embeds the puzzle input of every day that
+//! has a crate in the workspace, from `inputs/<year>/day<dd>.txt` under
+//! `AOC_DATA_DIR` or the workspace root. A day without an input is skipped
+//! with a warning.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=AOC_DATA_DIR");
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let mut table = String::from("&[\n");
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some() {
+        let inputs = env::var_os("AOC_DATA_DIR").map_or_else(|| root.clone(), PathBuf::from).join("inputs");
+        println!("cargo:rerun-if-changed={}", inputs.display());
+        for (year, day) in workspace_days(&root) {
+            let year_dir = inputs.join(year.to_string());
+            if year_dir.is_dir() {
+                println!("cargo:rerun-if-changed={}", year_dir.display());
+            }
+            let path = year_dir.join(format!("day{day:02}.txt"));
+            if !path.is_file() {
+                println!("cargo:warning=No input for {year}/{day:02} at {}, so it is not embedded", path.display());
+                continue;
+            }
+            println!("cargo:rerun-if-changed={}", path.display());
+            let path = fs::canonicalize(&path).unwrap();
+            writeln!(table, "    (DayId::new({year}, {day}), include_str!({:?})),", path.display().to_string()).unwrap();
+        }
+    }
+    table.push(']');
+
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("embedded_inputs.rs");
+    fs::write(out, table).unwrap();
+}
+
+/// The days with a `<year>/day<dd>` crate, in order.
+fn workspace_days(root: &Path) -> Vec<(u16, u8)> {
+    let mut days = vec![];
+    for entry in fs::read_dir(root).unwrap().flatten() {
+        let Some(year) = entry.file_name().to_str().and_then(|name| name.parse::<u16>().ok()) else {continue;};
+        for entry in fs::read_dir(entry.path()).unwrap().flatten() {
+            let name = entry.file_name();
+            let Some(day) = name.to_str().and_then(|name| name.strip_prefix("day")?.parse::<u8>().ok()) else {continue;};
+            if entry.path().join("Cargo.toml").is_file() {
+                days.push((year, day));
+            }
+        }
+    }
+    days.sort();
+    days
+}
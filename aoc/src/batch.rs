@@ -0,0 +1,52 @@
+//! Runs one day over a directory of inputs, e.g. collected from teammates, to
+//! check that the solution doesn't rely on quirks of a single input.
+
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use rayon::prelude::*;
+use runner::{Answers, Budget, DayId, Report};
+
+pub fn run(id: DayId, dir: &Path, expected: Option<&Path>, budget: Budget) -> Result<ExitCode, Box<dyn Error>> {
+    let day = aoc::find(id).ok_or_else(|| format!("No solution for {id}"))?;
+
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .filter(|path| path.as_ref().map_or(true, |path| path.is_file()))
+        .collect::<Result<_, _>>()?;
+    paths.sort();
+
+    let results: Vec<Result<Report, String>> = paths.par_iter()
+        .map(|path| {
+            let input = fs::read_to_string(path).map_err(|err| err.to_string())?;
+            day.run_with_budget(&input, budget).map_err(|err| err.to_string())
+        })
+        .collect();
+
+    let names: Vec<String> = paths.iter()
+        .map(|path| path.file_name().unwrap_or_default().to_string_lossy().into_owned())
+        .collect();
+    let width = names.iter().map(String::len).max().unwrap_or(0);
+
+    let mut all_correct = true;
+    for ((name, path), result) in names.iter().zip(&paths).zip(results) {
+        let label = format!("{name:<width$}");
+        let report = match result {
+            Ok(report) => report,
+            Err(err) => {
+                println!("{label}  error: {err}");
+                all_correct = false;
+                continue;
+            }
+        };
+        let known = match expected {
+            Some(dir) => Answers::load_from(&dir.join(path.file_name().unwrap_or_default()))?,
+            None => Answers::default(),
+        };
+        all_correct &= crate::print_parts(&label, &report, &known);
+    }
+
+    Ok(if all_correct {ExitCode::SUCCESS} else {ExitCode::FAILURE})
+}
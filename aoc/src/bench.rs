@@ -0,0 +1,74 @@
+//! Times every variant of a day's parts side by side, and checks that they
+//! give the same answers.
+
+use std::error::Error;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use runner::{Budget, Day, DEFAULT_VARIANT};
+
+/// How long one variant of a part took, over several runs.
+pub struct Measurement {
+    pub part: usize,
+    pub variant: &'static str,
+    pub answer: String,
+    pub median: Duration,
+    pub timed_out: bool,
+}
+
+/// Solves each part with each of its variants `runs` times. Returns the
+/// median parse time and a measurement per variant. A variant that runs out
+/// of budget isn't run again.
+pub fn measure(day: &Day, input: &str, runs: usize, budget: Budget) -> Result<(Duration, Vec<Measurement>), Box<dyn Error>> {
+    let mut parse_times = vec![];
+    let mut measurements = vec![];
+    for part in 1..=day.puzzle.parts.len() {
+        for variant in day.variants(part) {
+            let mut times = vec![];
+            let mut last = None;
+            for _ in 0..runs.max(1) {
+                let (parse_time, report) = day.run_variant(input, part, variant, budget)?;
+                let Some(report) = report else {break;};
+                parse_times.push(parse_time);
+                times.push(report.time);
+                let timed_out = report.timed_out;
+                last = Some(report);
+                if timed_out {
+                    break;
+                }
+            }
+            let Some(report) = last else {continue;};
+            measurements.push(Measurement{part, variant, answer: report.answer, median: median(times), timed_out: report.timed_out});
+        }
+    }
+    Ok((median(parse_times), measurements))
+}
+
+fn median(mut times: Vec<Duration>) -> Duration {
+    times.sort();
+    times.get(times.len() / 2).copied().unwrap_or_default()
+}
+
+pub fn run(day: &Day, input: &str, runs: usize, budget: Budget) -> Result<ExitCode, Box<dyn Error>> {
+    let (parse_time, measurements) = measure(day, input, runs, budget)?;
+    let width = measurements.iter().map(|m| m.variant.len()).max().unwrap_or(0);
+
+    println!("{}  parse   {:<width$}  {:>12?}", day.id, "", parse_time);
+    let mut all_agree = true;
+    for m in &measurements {
+        let default = measurements.iter()
+            .find(|other| other.part == m.part && other.variant == DEFAULT_VARIANT && !other.timed_out);
+        let status = match default {
+            _ if m.timed_out || m.variant == DEFAULT_VARIANT => String::new(),
+            None => String::from("?"),
+            Some(default) if default.answer == m.answer => String::from("ok"),
+            Some(default) => {
+                all_agree = false;
+                format!("DIFFERS (default gives {})", default.answer)
+            }
+        };
+        let row = format!("{}  part {}  {:<width$}  {:>12?}  {:<20} {status}", day.id, m.part, m.variant, m.median, m.answer);
+        println!("{}", row.trim_end());
+    }
+    Ok(if all_agree {ExitCode::SUCCESS} else {ExitCode::FAILURE})
+}
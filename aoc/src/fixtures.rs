@@ -0,0 +1,39 @@
+//! Solutions that only exist to test how failing solvers are handled, from
+//! outside the process: the `aoc` binary and the C API. They are only in the
+//! registry when `AOC_FIXTURES` is set.
+
+use std::error::Error;
+
+use runner::{AnswerKind, Day, DayId, InputShape, Puzzle, Solution};
+
+/// Whether the fixtures are in the registry.
+pub fn enabled() -> bool {
+    std::env::var_os("AOC_FIXTURES").is_some()
+}
+
+pub fn days() -> Vec<Day> {
+    vec![Day::of::<Panics>()]
+}
+
+/// Sums the numbers, one per line, and panics on a 0. Anything that isn't a
+/// number is invalid.
+struct Panics;
+impl Solution for Panics {
+    const ID: DayId = DayId::new(2000, 1);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Panics",
+        parts: &[AnswerKind::Integer],
+        parameters: &[],
+        input: InputShape::Lines,
+    };
+    type Input<'a> = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        assert!(!input.contains(&0), "found a 0");
+        input.iter().sum::<u64>().to_string()
+    }
+}
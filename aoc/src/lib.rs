@@ -0,0 +1,81 @@
+//! Registry of every solution in the repository, across all years.
+
+use std::error::Error;
+use std::io;
+
+use runner::{Answers, Budget, Day, DayId, DEFAULT_VARIANT};
+
+mod fixtures;
+pub mod minimise;
+pub mod plugins;
+
+/// Advent of Code has 25 puzzles every year.
+pub const DAYS_PER_YEAR: u8 = 25;
+
+/// Every day, sorted by id, with the [test fixtures](fixtures) first if they
+/// are enabled.
+pub fn days() -> Vec<Day> {
+    let mut days = if fixtures::enabled() {fixtures::days()} else {vec![]};
+    days.extend(aoc2024::days());
+    days
+}
+
+pub fn find(id: DayId) -> Option<Day> {
+    days().into_iter().find(|day| day.id == id)
+}
+
+/// Solves every part of a day with each of its variants, and describes each
+/// answer that differs from the default variant's. Variants that run out of
+/// budget are left out.
+pub fn cross_check(day: &Day, input: &str, budget: Budget) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut differences = vec![];
+    for part in 1..=day.puzzle.parts.len() {
+        let mut expected = None;
+        for variant in day.variants(part) {
+            let Some(report) = day.run_variant(input, part, variant, budget)?.1 else {continue;};
+            if report.timed_out {
+                continue;
+            }
+            match &expected {
+                None if variant == DEFAULT_VARIANT => expected = Some(report.answer),
+                Some(expected) if *expected != report.answer => differences.push(format!(
+                    "{} part {part}: {variant} gives {}, but {DEFAULT_VARIANT} gives {expected}", day.id, report.answer,
+                )),
+                _ => {}
+            }
+        }
+    }
+    Ok(differences)
+}
+
+/// The inputs embedded with the `embed-inputs` feature, which is empty without
+/// it.
+const EMBEDDED_INPUTS: &[(DayId, &str)] = include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs"));
+
+/// Whether this build embeds inputs, even if no input was found to embed.
+pub const EMBEDS_INPUTS: bool = cfg!(feature = "embed-inputs");
+
+/// The input that was embedded for a day at build time, if any.
+pub fn embedded_input(id: DayId) -> Option<&'static str> {
+    EMBEDDED_INPUTS.iter().find(|(day, _)| *day == id).map(|(_, input)| *input)
+}
+
+/// Solves a day straight from a reader, without first reading the whole input
+/// into memory, for the days that can. Returns `None` for the other days.
+pub fn solve_stream(id: DayId, input: impl io::BufRead) -> Option<Result<Answers, Box<dyn Error>>> {
+    use aoc2024::{day01, day02, day03, day07, day09};
+
+    fn answers<A: ToString, B: ToString>((a, b): (A, B)) -> Answers {
+        Answers{parts: [Some(a.to_string()), Some(b.to_string())]}
+    }
+
+    let result = match (id.year, id.day) {
+        (2024, 1) => day01::solve_stream(input).map(answers),
+        (2024, 2) => day02::solve_stream(input).map(answers),
+        (2024, 3) => day03::solve_stream(input).map(answers),
+        (2024, 7) => day07::solve_stream(input).map(answers),
+        (2024, 9) => day09::solve_stream(input).map(answers),
+        _ => return None,
+    };
+    Some(result)
+}
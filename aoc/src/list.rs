@@ -0,0 +1,55 @@
+//! Prints which puzzles have solutions: a calendar of every year, followed by
+//! what is known about each day.
+
+use std::collections::BTreeSet;
+
+use runner::{Day, Puzzle};
+
+pub fn run(year: Option<u16>) {
+    let days: Vec<Day> = aoc::days().into_iter()
+        .filter(|day| year.is_none_or(|year| year == day.id.year))
+        .collect();
+    print!("{}", calendar(&days));
+    println!();
+    print!("{}", details(&days));
+}
+
+/// A row per year with a column per day, showing a star per implemented part.
+fn calendar(days: &[Day]) -> String {
+    let mut out = String::from("    ");
+    for day in 1..=aoc::DAYS_PER_YEAR {
+        out += &format!(" {day:>2}");
+    }
+    out.push('\n');
+
+    let years: BTreeSet<u16> = days.iter().map(|day| day.id.year).collect();
+    for year in years {
+        out += &year.to_string();
+        for n in 1..=aoc::DAYS_PER_YEAR {
+            let puzzle = days.iter().find(|day| day.id.year == year && day.id.day == n).map(|day| day.puzzle);
+            out += &format!(" {:>2}", puzzle.map_or(String::from("."), |puzzle| stars(&puzzle)));
+        }
+        out.push('\n');
+    }
+    out += "\n** both parts solved, * part 1 only, . not solved\n";
+    out
+}
+
+fn stars(puzzle: &Puzzle) -> String {
+    "*".repeat(puzzle.parts.len())
+}
+
+/// A row per day with its title, answer kinds and parameters.
+fn details(days: &[Day]) -> String {
+    let width = days.iter().map(|day| day.puzzle.title.len()).max().unwrap_or(0);
+    let mut out = String::new();
+    for day in days {
+        let puzzle = &day.puzzle;
+        let answers: Vec<String> = puzzle.parts.iter().map(ToString::to_string).collect();
+        let parameters: Vec<String> = puzzle.parameters.iter().map(ToString::to_string).collect();
+        let row = format!("{}  {:<width$}  {:<2}  {:<16}  {}", day.id, puzzle.title, stars(puzzle), answers.join(", "), parameters.join(" "));
+        out += row.trim_end();
+        out.push('\n');
+    }
+    out
+}
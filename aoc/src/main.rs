@@ -0,0 +1,493 @@
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{self, BufReader, IsTerminal};
+use std::panic;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use aoc::minimise::Failure;
+use aoc::plugins::{self, Plugin};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use runner::{Answers, Budget, Day, DayId, Report};
+use tracing::Level;
+use tracing_subscriber::filter::filter_fn;
+use tracing_subscriber::prelude::*;
+
+mod batch;
+mod bench;
+mod list;
+mod report;
+mod watch;
+mod progress;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    #[command(flatten)]
+    explain: ExplainArgs,
+    /// Give up on a part after this many seconds, and report it as timed out
+    #[arg(long, global = true, value_name = "SECS", value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+}
+
+#[derive(Args)]
+struct ExplainArgs {
+    /// Print trace events to stderr that explain how the answers were found,
+    /// optionally only for some days, e.g. `--explain=2024/05,2024/13`
+    #[arg(long, global = true, value_name = "DAYS", num_args = 0.., value_delimiter = ',', require_equals = true)]
+    explain: Option<Vec<DayId>>,
+    /// Include fine-grained trace events as well
+    #[arg(short, long, global = true)]
+    verbose: bool,
+    /// Format of the trace events
+    #[arg(long, global = true, value_enum, default_value_t = TraceFormat::Text)]
+    trace_format: TraceFormat,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum TraceFormat {
+    Text,
+    /// One JSON object per line
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum FailureArg {
+    /// Parsing or solving panics
+    Panic,
+    /// Parsing or solving fails with the same error
+    Error,
+    /// Two variants of a part give different answers
+    Mismatch,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, reading the puzzle input from stdin
+    Run {
+        year: u16,
+        day: u8,
+        /// Read the puzzle input from this file instead
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Use the puzzle input embedded in the binary instead, see
+        /// `run-all --embedded`
+        #[arg(long, conflicts_with = "input")]
+        embedded: bool,
+        /// Save the answers to the answers store
+        #[arg(long)]
+        save: bool,
+        /// Solve while reading the input, without holding all of it in
+        /// memory. Only some days can: 2024/01, 02, 03, 07 and 09
+        #[arg(long)]
+        stream: bool,
+        /// Solve the parts that have this variant with it, see `bench`
+        #[arg(long, conflicts_with = "stream")]
+        variant: Option<String>,
+    },
+    /// Solve every day that has an input in the inputs store, and check the
+    /// answers against the answers store
+    RunAll {
+        /// Only run the days of this year
+        #[arg(long)]
+        year: Option<u16>,
+        /// Use the puzzle inputs embedded in the binary instead of the inputs
+        /// store. Needs a build with `--features embed-inputs`
+        #[arg(long)]
+        embedded: bool,
+    },
+    /// Solve one day for every input file in a directory, in parallel
+    Batch {
+        year: u16,
+        day: u8,
+        dir: PathBuf,
+        /// Check the answers against files of the same name in this
+        /// directory, in the answers store format
+        #[arg(long, value_name = "DIR")]
+        expected: Option<PathBuf>,
+    },
+    /// Time every variant of a day's parts side by side, and check that they
+    /// agree, reading the puzzle input from stdin
+    Bench {
+        year: u16,
+        day: u8,
+        /// Read the puzzle input from this file instead
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Use the puzzle input embedded in the binary instead
+        #[arg(long, conflicts_with = "input")]
+        embedded: bool,
+        /// Take the median time of this many runs
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+    },
+    /// Step through a day's simulation interactively in the terminal. Only
+    /// some days are simulations: 2024/06, 2024/15 and 2024/17
+    Step {
+        year: u16,
+        day: u8,
+        /// Read the puzzle input from this file instead of stdin
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Solve a day again whenever one of its input files changes, showing
+    /// which answers changed
+    Watch {
+        year: u16,
+        day: u8,
+        /// Watch this file instead of the day's input in the inputs store.
+        /// Can be given more than once
+        #[arg(long)]
+        input: Vec<PathBuf>,
+    },
+    /// Write a Markdown report of every day's stars, solved parts and times,
+    /// solving each day that has an input in the inputs store
+    Report {
+        /// Only report on the days of this year
+        #[arg(long)]
+        year: Option<u16>,
+        /// Use the puzzle inputs embedded in the binary instead of the inputs
+        /// store
+        #[arg(long)]
+        embedded: bool,
+        /// Take the median time of this many runs
+        #[arg(long, default_value_t = 5)]
+        runs: usize,
+        /// Write the report to this file instead of stdout. Its links work
+        /// from the workspace root
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Show a calendar of the solved puzzles, and what is known about each
+    List {
+        /// Only list the days of this year
+        #[arg(long)]
+        year: Option<u16>,
+    },
+    /// Cut down an input that a day fails on to a small one that it still
+    /// fails on in the same way. Each run gives up after 10 seconds, unless
+    /// `--timeout` says otherwise
+    Minimise {
+        year: u16,
+        day: u8,
+        input: PathBuf,
+        /// The way the day fails, which must keep happening
+        #[arg(long, value_enum)]
+        failure: FailureArg,
+        /// Write the smallest failing input to this file instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// List the solver plugins in the plugins directory, and the parts they
+    /// solve. `run` checks them against the built-in solvers
+    Plugins,
+}
+
+fn main() -> Result<ExitCode, Box<dyn Error>> {
+    let cli = Cli::parse();
+    if let Some(days) = cli.explain.explain {
+        init_tracing(days, cli.explain.verbose, cli.explain.trace_format);
+    }
+
+    let budget = cli.timeout.map_or(Budget::UNLIMITED, Budget::time);
+
+    match cli.command {
+        Command::Run{year, day, input, embedded, save, stream: false, variant} => {
+            run(DayId::new(year, day), input, embedded, save, variant.as_deref(), &load_plugins()?, budget)
+        }
+        Command::Run{year, day, input, embedded, save, stream: true, variant: _} => {
+            run_stream(DayId::new(year, day), input, embedded, save)
+        }
+        Command::RunAll{year, embedded} => run_all(year, embedded, budget),
+        Command::Batch{year, day, dir, expected} => {
+            batch::run(DayId::new(year, day), &dir, expected.as_deref(), budget)
+        }
+        Command::Bench{year, day, input, embedded, runs} => {
+            let id = DayId::new(year, day);
+            let day = aoc::find(id).ok_or_else(|| format!("No solution for {id}"))?;
+            let input = if embedded {embedded_input(id)?.to_string()} else {read_input(input)?};
+            bench::run(&day, &input, runs, budget)
+        }
+        Command::Step{year, day, input} => {
+            stepper::open(DayId::new(year, day), &read_input(input)?)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Watch{year, day, input} => {
+            let id = DayId::new(year, day);
+            let day = aoc::find(id).ok_or_else(|| format!("No solution for {id}"))?;
+            let paths = if input.is_empty() {vec![id.input_path()]} else {input};
+            watch::run(&day, &paths, budget)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Report{year, embedded, runs, output} => {
+            if embedded {
+                check_embeds_inputs()?;
+            }
+            report::run(year, embedded, runs, output.as_deref(), budget)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::List{year} => {
+            list::run(year);
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Minimise{year, day, input, failure, output} => {
+            let id = DayId::new(year, day);
+            let day = aoc::find(id).ok_or_else(|| format!("No solution for {id}"))?;
+            let failure = match failure {
+                FailureArg::Panic => Failure::Panic,
+                FailureArg::Error => Failure::Error,
+                FailureArg::Mismatch => Failure::Mismatch,
+            };
+            let budget = cli.timeout.map_or(Budget::time(MINIMISE_TIMEOUT), Budget::time);
+            minimise(&day, &fs::read_to_string(input)?, failure, output, budget)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Plugins => {
+            list_plugins(&load_plugins()?);
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    let secs: f64 = s.parse().map_err(|_| format!("invalid number of seconds: {s}"))?;
+    Duration::try_from_secs_f64(secs).map_err(|err| err.to_string())
+}
+
+fn run(id: DayId, input: Option<PathBuf>, embedded: bool, save: bool, variant: Option<&str>, plugins: &[Plugin], budget: Budget) -> Result<ExitCode, Box<dyn Error>> {
+    let day = aoc::find(id).ok_or_else(|| format!("No solution for {id}"))?;
+    let input = if embedded {embedded_input(id)?.to_string()} else {read_input(input)?};
+
+    let report = progress::show(&id.to_string(), || match variant {
+        Some(variant) => day.run_with_variant(&input, variant, budget),
+        None => day.run_with_budget(&input, budget),
+    })?;
+    print!("{}", report.answers());
+    eprintln!("Parsed in {:?}", report.parse_time);
+    for (i, part) in report.parts.iter().enumerate() {
+        if part.timed_out {
+            eprintln!("Part {} timed out after {:?}", i + 1, part.time);
+        } else if part.failed {
+            eprintln!("Part {} failed after {:?}: {}", i + 1, part.time, part.answer);
+        } else {
+            eprintln!("Part {} solved in {:?}", i + 1, part.time);
+        }
+    }
+
+    if save {
+        report.answers().save(id)?;
+    }
+    let all_answered = report.parts.iter().all(|part| part.is_answer());
+    let plugins_agree = check_plugins(id, &input, &report, plugins);
+    Ok(if !all_answered || !plugins_agree {ExitCode::FAILURE} else {ExitCode::SUCCESS})
+}
+
+/// How long each run of a day may take while minimising an input, as cutting
+/// an input down can leave one that a solver never finishes on.
+const MINIMISE_TIMEOUT: Duration = Duration::from_secs(10);
+
+fn minimise(day: &Day, input: &str, failure: Failure, output: Option<PathBuf>, budget: Budget) -> Result<(), Box<dyn Error>> {
+    // Every panicking run would otherwise print its panic message.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let minimised = aoc::minimise::minimise(day, input, failure, budget);
+    panic::set_hook(hook);
+    let minimised = minimised?;
+
+    match output {
+        Some(path) => fs::write(path, &minimised.input)?,
+        None => print!("{}", minimised.input),
+    }
+    eprintln!("Cut down from {} to {} bytes in {} runs", input.len(), minimised.input.len(), minimised.runs);
+    eprintln!("{}", minimised.failure);
+    Ok(())
+}
+
+/// Loads the plugins in the plugins directory, warning about any that can't
+/// be loaded.
+fn load_plugins() -> io::Result<Vec<Plugin>> {
+    // SAFETY: whatever is in the plugins directory was put there to be run.
+    let loaded = unsafe {plugins::load_dir(&plugins::plugin_dir())?};
+    let mut plugins = vec![];
+    for plugin in loaded {
+        match plugin {
+            Ok(plugin) => plugins.push(plugin),
+            Err(err) => eprintln!("Skipping plugin {err}"),
+        }
+    }
+    Ok(plugins)
+}
+
+fn list_plugins(plugins: &[Plugin]) {
+    if plugins.is_empty() {
+        println!("No plugins in {}", plugins::plugin_dir().display());
+    }
+    for plugin in plugins {
+        println!("{}  {}", plugin.id, plugin.name());
+        for (part, variant) in &plugin.variants {
+            println!("    part {part}  {variant}");
+        }
+    }
+}
+
+/// Solves the day with each of its plugins, and prints how their answers
+/// compare with the built-in ones in `report`. Returns false if any differ.
+fn check_plugins(id: DayId, input: &str, report: &Report, plugins: &[Plugin]) -> bool {
+    let mut all_agree = true;
+    for plugin in plugins.iter().filter(|plugin| plugin.id == id) {
+        for check in plugin.check(input, report) {
+            all_agree &= check.agrees();
+            let status = match (&check.answer, &check.expected) {
+                (Err(err), _) => format!("error: {err}"),
+                (Ok(answer), None) => format!("{answer} in {:?}  ?", check.time),
+                (Ok(answer), Some(expected)) if answer == expected => format!("{answer} in {:?}  ok", check.time),
+                (Ok(answer), Some(expected)) => format!("{answer} in {:?}  DIFFERS (built-in gives {expected})", check.time),
+            };
+            eprintln!("Plugin {} part {} {}: {status}", plugin.name(), check.part, check.variant);
+        }
+    }
+    all_agree
+}
+
+/// The input embedded for a day, or an error saying why there is none.
+fn embedded_input(id: DayId) -> Result<&'static str, String> {
+    check_embeds_inputs()?;
+    aoc::embedded_input(id).ok_or_else(|| format!("No input was embedded for {id}"))
+}
+
+fn check_embeds_inputs() -> Result<(), String> {
+    if aoc::EMBEDS_INPUTS {
+        Ok(())
+    } else {
+        Err(String::from("This binary has no embedded inputs, build it with `--features embed-inputs`"))
+    }
+}
+
+/// Reads the puzzle input from a file, or from stdin if there is none.
+fn read_input(path: Option<PathBuf>) -> io::Result<String> {
+    match path {
+        Some(path) => fs::read_to_string(path),
+        None => io::read_to_string(io::stdin().lock()),
+    }
+}
+
+fn run_stream(id: DayId, input: Option<PathBuf>, embedded: bool, save: bool) -> Result<ExitCode, Box<dyn Error>> {
+    let start = Instant::now();
+    let answers = match input {
+        _ if embedded => aoc::solve_stream(id, embedded_input(id)?.as_bytes()),
+        Some(path) => aoc::solve_stream(id, BufReader::new(File::open(path)?)),
+        None => aoc::solve_stream(id, io::stdin().lock()),
+    };
+    let answers = answers.ok_or_else(|| format!("{id} can't be solved from a stream"))??;
+    print!("{answers}");
+    eprintln!("Solved in {:?}", start.elapsed());
+
+    if save {
+        answers.save(id)?;
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn run_all(year: Option<u16>, embedded: bool, budget: Budget) -> Result<ExitCode, Box<dyn Error>> {
+    if embedded {
+        check_embeds_inputs()?;
+    }
+    let mut all_correct = true;
+    for day in aoc::days() {
+        if year.is_some_and(|year| year != day.id.year) {
+            continue;
+        }
+
+        let input = if embedded {
+            let Some(input) = aoc::embedded_input(day.id) else {
+                println!("{}  no embedded input", day.id);
+                continue;
+            };
+            input.to_string()
+        } else {
+            let path = day.id.input_path();
+            if !path.exists() {
+                println!("{}  no input at {}", day.id, path.display());
+                continue;
+            }
+            fs::read_to_string(path)?
+        };
+        let known = Answers::load(day.id)?;
+
+        let report = match progress::show(&day.id.to_string(), || day.run_with_budget(&input, budget)) {
+            Ok(report) => report,
+            Err(err) => {
+                println!("{}  error: {err}", day.id);
+                all_correct = false;
+                continue;
+            }
+        };
+
+        all_correct &= print_parts(&day.id.to_string(), &report, &known);
+    }
+
+    Ok(if all_correct {ExitCode::SUCCESS} else {ExitCode::FAILURE})
+}
+
+/// Prints a row for each part of `report`, checking it against `known`.
+/// Returns false if any answer is wrong, timed out or failed.
+fn print_parts(label: &str, report: &Report, known: &Answers) -> bool {
+    let mut all_correct = true;
+    for (i, part) in report.parts.iter().enumerate() {
+        if part.timed_out {
+            println!("{label}  part {}  timed out after {:?}", i + 1, part.time);
+            all_correct = false;
+            continue;
+        }
+        if part.failed {
+            println!("{label}  part {}  error: {}", i + 1, part.answer);
+            all_correct = false;
+            continue;
+        }
+        let status = match known.get(i + 1) {
+            None => String::from("?"),
+            Some(expected) if expected == part.answer => String::from("ok"),
+            Some(expected) => {
+                all_correct = false;
+                format!("WRONG (expected {expected})")
+            }
+        };
+        println!("{label}  part {}  {:<20} {:>12?}  {status}", i + 1, part.answer, part.time);
+    }
+    all_correct
+}
+
+/// Sends trace events from the given days' solvers to stderr, or from every
+/// solver if `days` is empty. Spans are always enabled, so that each event
+/// records which day and part it came from.
+fn init_tracing(days: Vec<DayId>, verbose: bool, format: TraceFormat) {
+    let max_level = if verbose {Level::TRACE} else {Level::DEBUG};
+    let targets: Vec<String> = days.into_iter().map(DayId::trace_target).collect();
+
+    let filter = filter_fn(move |meta| {
+        if meta.is_span() {
+            return true;
+        }
+        let target = meta.target();
+        let is_selected = targets.is_empty() || targets.iter().any(|day| {
+            target.strip_prefix(day.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+        });
+        is_selected && *meta.level() <= max_level
+    });
+
+    let layer = tracing_subscriber::fmt::layer()
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .without_time();
+    let layer = match format {
+        TraceFormat::Text => layer.boxed(),
+        TraceFormat::Json => layer.json().boxed(),
+    };
+    tracing_subscriber::registry()
+        .with(layer.with_filter(filter))
+        .init();
+}
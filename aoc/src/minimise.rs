@@ -0,0 +1,215 @@
+//! Cuts down an input that a day fails on to a small one that it still fails
+//! on in the same way, following the day's [`InputShape`] so that what is
+//! left is still laid out like a puzzle input.
+//!
+//! Lines, items, grid rows and columns, and blocks are removed in runs that
+//! get shorter, down to one at a time, for as long as the failure persists.
+//! Each section, line and grid keeps at least one of whatever it is made of.
+
+use std::error::Error;
+use std::fmt;
+
+use runner::{Budget, Day, InputShape, Panicked};
+
+/// A way for a day to fail on an input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Failure {
+    /// Parsing, or solving a part with any of its variants, panics.
+    Panic,
+    /// Parsing, or solving a part with any of its variants, fails with an
+    /// error, which must stay the same while minimising.
+    Error,
+    /// Two variants of a part give different answers.
+    Mismatch,
+}
+
+impl Failure {
+    /// Describes how `day` fails on `input` in this way, or `None` if it
+    /// doesn't. Parts that run out of `budget` don't fail.
+    pub fn find(self, day: &Day, input: &str, budget: Budget) -> Option<String> {
+        if self == Failure::Mismatch {
+            return crate::cross_check(day, input, budget).ok()?.into_iter().next();
+        }
+        for part in 1..=day.puzzle.parts.len() {
+            for variant in day.variants(part) {
+                match day.run_variant(input, part, variant, budget) {
+                    Err(err) if err.is::<Panicked>() == (self == Failure::Panic) => return Some(err.to_string()),
+                    Ok((_, Some(report))) if report.failed && self == Failure::Error => return Some(report.answer),
+                    _ => {}
+                }
+            }
+        }
+        None
+    }
+}
+
+/// The smallest input that was found to fail.
+#[derive(Debug, Clone)]
+pub struct Minimised {
+    pub input: String,
+    /// How it fails.
+    pub failure: String,
+    /// How many inputs were tried.
+    pub runs: usize,
+}
+
+/// Minimises `input`, which `day` must fail on in the way of `failure`.
+pub fn minimise(day: &Day, input: &str, failure: Failure, budget: Budget) -> Result<Minimised, Box<dyn Error>> {
+    let Some(original) = failure.find(day, input, budget) else {
+        let what = match failure {
+            Failure::Panic => "panic",
+            Failure::Error => "fail with an error",
+            Failure::Mismatch => "give different answers with different variants",
+        };
+        return Err(format!("{} doesn't {what} on this input", day.id).into());
+    };
+    let mut runs = 1;
+    let mut fails = |input: &str| {
+        runs += 1;
+        failure.find(day, input, budget).is_some_and(|found| failure != Failure::Error || found == original)
+    };
+
+    let mut sections = split(&input.replace("\r\n", "\n"), day.puzzle.input);
+    loop {
+        let mut progress = false;
+        for i in 0..sections.len() {
+            let mut section = sections[i].clone();
+            progress |= section.shrink(&mut |candidate| {
+                let mut all = sections.clone();
+                all[i] = candidate.clone();
+                fails(&join(&all))
+            });
+            sections[i] = section;
+        }
+        if !progress {
+            break;
+        }
+    }
+
+    let input = join(&sections);
+    let failure = failure.find(day, &input, budget).unwrap_or(original);
+    Ok(Minimised{input, failure, runs})
+}
+
+/// A part of an input that is cut down on its own.
+#[derive(Debug, Clone)]
+enum Section {
+    /// Lines, or blocks of lines, that are kept or removed whole, joined by
+    /// `separator`.
+    Units{units: Vec<String>, separator: &'static str},
+    /// Lines of items joined by `separator`.
+    Items{lines: Vec<Vec<String>>, separator: &'static str},
+    Grid(Vec<Vec<char>>),
+}
+
+fn split(input: &str, shape: InputShape) -> Vec<Section> {
+    let input = input.trim_end_matches('\n');
+    match shape {
+        InputShape::Sections(shapes) => {
+            input.splitn(shapes.len(), "\n\n").zip(shapes).map(|(text, &shape)| section(text, shape)).collect()
+        }
+        shape => vec![section(input, shape)],
+    }
+}
+
+fn section(text: &str, shape: InputShape) -> Section {
+    let text = text.trim_matches('\n');
+    match shape {
+        InputShape::Lines | InputShape::Sections(_) => Section::Units{units: text.lines().map(String::from).collect(), separator: "\n"},
+        InputShape::Blocks => Section::Units{units: text.split("\n\n").map(|block| block.trim_matches('\n').to_string()).collect(), separator: "\n\n"},
+        InputShape::Items{separator} => Section::Items{
+            lines: text.lines().map(|line| if separator.is_empty() {
+                line.chars().map(String::from).collect()
+            } else {
+                line.split(separator).map(String::from).collect()
+            }).collect(),
+            separator,
+        },
+        InputShape::Grid => Section::Grid(text.lines().map(|line| line.chars().collect()).collect()),
+    }
+}
+
+fn join(sections: &[Section]) -> String {
+    let sections: Vec<String> = sections.iter().map(Section::to_string).collect();
+    sections.join("\n\n") + "\n"
+}
+
+impl fmt::Display for Section {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Section::Units{units, separator} => f.write_str(&units.join(separator)),
+            Section::Items{lines, separator} => {
+                let lines: Vec<String> = lines.iter().map(|items| items.join(separator)).collect();
+                f.write_str(&lines.join("\n"))
+            }
+            Section::Grid(rows) => {
+                let rows: Vec<String> = rows.iter().map(|row| row.iter().collect()).collect();
+                f.write_str(&rows.join("\n"))
+            }
+        }
+    }
+}
+
+impl Section {
+    /// Removes what it can while `fails` holds for the section that is left.
+    /// Returns whether anything was removed.
+    fn shrink(&mut self, fails: &mut impl FnMut(&Section) -> bool) -> bool {
+        match self {
+            Section::Units{units, separator} => {
+                let separator = *separator;
+                remove_runs(units, |units| fails(&Section::Units{units: units.to_vec(), separator}))
+            }
+            Section::Items{lines, separator} => {
+                let separator = *separator;
+                let mut changed = remove_runs(lines, |lines| fails(&Section::Items{lines: lines.to_vec(), separator}));
+                for i in 0..lines.len() {
+                    let mut items = lines[i].clone();
+                    changed |= remove_runs(&mut items, |items| {
+                        let mut lines = lines.clone();
+                        lines[i] = items.to_vec();
+                        fails(&Section::Items{lines, separator})
+                    });
+                    lines[i] = items;
+                }
+                changed
+            }
+            Section::Grid(rows) => {
+                let mut changed = remove_runs(rows, |rows| fails(&Section::Grid(rows.to_vec())));
+                let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+                let mut columns: Vec<usize> = (0..width).collect();
+                if remove_runs(&mut columns, |columns| fails(&Section::Grid(select_columns(rows, columns)))) {
+                    *rows = select_columns(rows, &columns);
+                    changed = true;
+                }
+                changed
+            }
+        }
+    }
+}
+
+fn select_columns(rows: &[Vec<char>], columns: &[usize]) -> Vec<Vec<char>> {
+    rows.iter().map(|row| columns.iter().filter_map(|&j| row.get(j).copied()).collect()).collect()
+}
+
+/// Removes runs of `items`, halving the length of the runs tried each time,
+/// while `fails` holds for what is left. Always keeps at least one item.
+/// Returns whether anything was removed.
+fn remove_runs<T: Clone>(items: &mut Vec<T>, mut fails: impl FnMut(&[T]) -> bool) -> bool {
+    let mut changed = false;
+    let mut len = items.len() / 2;
+    while len > 0 {
+        let mut start = 0;
+        while start < items.len() && len < items.len() {
+            let end = (start + len).min(items.len());
+            let rest: Vec<T> = items[..start].iter().chain(&items[end..]).cloned().collect();
+            if fails(&rest) {
+                *items = rest;
+                changed = true;
+            } else {
+                start = end;
+            }
+        }
+        len /= 2;
+    }
+    changed
+}
@@ -0,0 +1,178 @@
+//! Solver plugins, loaded from shared libraries in the plugins directory. See
+//! the `aoc-plugin` crate for how to write one.
+
+use std::error::Error;
+use std::ffi::{c_char, CStr};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::time::{Duration, Instant};
+
+use aoc_plugin::{Declaration, ABI_VERSION, DECLARATION_SYMBOL};
+use libloading::Library;
+use runner::{DayId, Report};
+
+/// A loaded plugin, which stays loaded until it is dropped.
+pub struct Plugin {
+    pub path: PathBuf,
+    pub id: DayId,
+    /// The plugin's variants as `(part, name)`, in the plugin's order.
+    pub variants: Vec<(usize, String)>,
+    declaration: *const Declaration,
+    /// Kept last, so that nothing pointing into it outlives it.
+    _library: Library,
+}
+
+/// One of a plugin's answers, next to the built-in solver's.
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub part: usize,
+    pub variant: String,
+    /// The plugin's answer, or its error message.
+    pub answer: Result<String, String>,
+    pub time: Duration,
+    /// The built-in answer, or `None` if it timed out, failed, or the day has
+    /// no such part.
+    pub expected: Option<String>,
+}
+
+impl Check {
+    /// Whether the plugin gave the built-in answer. A check against nothing
+    /// agrees, as there is nothing to disagree with.
+    pub fn agrees(&self) -> bool {
+        match (&self.answer, &self.expected) {
+            (Ok(answer), Some(expected)) => answer == expected,
+            (Ok(_), None) => true,
+            (Err(_), _) => false,
+        }
+    }
+}
+
+/// Why the library at `path` couldn't be loaded as a plugin.
+#[derive(Debug)]
+pub struct LoadError {
+    pub path: PathBuf,
+    pub error: Box<dyn Error>,
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.error)
+    }
+}
+
+impl Error for LoadError {}
+
+/// The plugins directory, `plugins/` in the [data directory](runner::data_dir).
+pub fn plugin_dir() -> PathBuf {
+    runner::data_dir().join("plugins")
+}
+
+/// Loads every shared library in `dir`, in order of file name. A missing
+/// directory has no plugins.
+///
+/// # Safety
+///
+/// Loading a library runs its initialisation code, and calling into it
+/// trusts that it was built against this version of `aoc-plugin`'s ABI, so
+/// every shared library in `dir` must be a trusted plugin.
+pub unsafe fn load_dir(dir: &Path) -> io::Result<Vec<Result<Plugin, LoadError>>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err),
+    };
+    let mut paths = vec![];
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == std::env::consts::DLL_EXTENSION) {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths.into_iter().map(|path| Plugin::load(&path).map_err(|error| LoadError{path, error})).collect())
+}
+
+impl Plugin {
+    /// Loads the plugin at `path`, checking that it declares the ABI version
+    /// this runner understands.
+    ///
+    /// # Safety
+    ///
+    /// As for [`load_dir`].
+    pub unsafe fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let library = Library::new(path)?;
+        let declare = library.get::<extern "C" fn() -> *const Declaration>(DECLARATION_SYMBOL)?;
+        let declaration = declare();
+        if declaration.is_null() {
+            return Err("The plugin declared nothing".into());
+        }
+        // Only the version is read until it is known to match, as the rest
+        // of the declaration may be laid out differently.
+        let abi_version = ptr::addr_of!((*declaration).abi_version).read();
+        if abi_version != ABI_VERSION {
+            return Err(format!("The plugin has ABI version {abi_version}, but only version {ABI_VERSION} is supported").into());
+        }
+
+        let Declaration{year, day, variants, variant_count, ..} = &*declaration;
+        if !(1..=crate::DAYS_PER_YEAR).contains(day) {
+            return Err(format!("The plugin is for day {day}, which doesn't exist").into());
+        }
+        let variants = if *variant_count == 0 {&[][..]} else {std::slice::from_raw_parts(*variants, *variant_count)};
+        let variants = variants.iter()
+            .map(|variant| {
+                let name = variant_name(variant.name)?;
+                match variant.part {
+                    0 => Err(format!("The plugin's variant {name:?} is for part 0, but parts are numbered from 1").into()),
+                    part => Ok((usize::from(part), name)),
+                }
+            })
+            .collect::<Result<_, Box<dyn Error>>>()?;
+
+        Ok(Self{path: path.to_owned(), id: DayId::new(*year, *day), variants, declaration, _library: library})
+    }
+
+    /// Solves a part with the plugin's `variant`, which indexes
+    /// [`variants`](Self::variants). Returns the plugin's error message if it
+    /// fails. Plugins aren't given a budget, so this can't time out.
+    pub fn solve(&self, variant: usize, input: &str) -> Result<String, String> {
+        // SAFETY: the declaration was checked when the plugin was loaded, and
+        // the library is still loaded.
+        unsafe {
+            let declaration = &*self.declaration;
+            let mut answer = ptr::null_mut();
+            let ok = (declaration.solve)(variant, input.as_ptr(), input.len(), &mut answer);
+            let text = if answer.is_null() {String::new()} else {CStr::from_ptr(answer).to_string_lossy().into_owned()};
+            (declaration.free_string)(answer);
+            if ok {Ok(text)} else {Err(text)}
+        }
+    }
+
+    /// Solves every part the plugin has a variant for, and compares the
+    /// answers with the built-in ones in `report`, from the same input.
+    pub fn check(&self, input: &str, report: &Report) -> Vec<Check> {
+        self.variants.iter().enumerate().map(|(i, (part, name))| {
+            let start = Instant::now();
+            let answer = self.solve(i, input);
+            let time = start.elapsed();
+            let expected = report.parts.get(part - 1)
+                .filter(|part| part.is_answer())
+                .map(|part| part.answer.clone());
+            Check{part: *part, variant: name.clone(), answer, time, expected}
+        }).collect()
+    }
+
+    /// The file name the plugin was loaded from, to tell plugins apart.
+    pub fn name(&self) -> String {
+        self.path.file_name().unwrap_or(self.path.as_os_str()).to_string_lossy().into_owned()
+    }
+}
+
+unsafe fn variant_name(name: *const c_char) -> Result<String, Box<dyn Error>> {
+    if name.is_null() {
+        return Err("The plugin has a variant without a name".into());
+    }
+    Ok(CStr::from_ptr(name).to_str()?.to_owned())
+}
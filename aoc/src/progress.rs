@@ -0,0 +1,37 @@
+//! A progress bar on stderr for solvers that report their progress.
+
+use std::io::{self, IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+/// Redrawing more often than this would only slow the solver down.
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+const BAR_WIDTH: u64 = 30;
+
+/// Runs `f`, showing the progress it reports as a bar labelled `label` if
+/// stderr is a terminal. The bar is erased when `f` returns.
+pub fn show<T>(label: &str, f: impl FnOnce() -> T) -> T {
+    if !io::stderr().is_terminal() {
+        return f();
+    }
+
+    let label = label.to_string();
+    let mut last_draw: Option<Instant> = None;
+    let result = runner::progress::with_reporter(
+        move |done, total| {
+            if total == 0 || last_draw.is_some_and(|time| time.elapsed() < REDRAW_INTERVAL) {
+                return;
+            }
+            last_draw = Some(Instant::now());
+            let done = done.min(total);
+            let filled = (done * BAR_WIDTH / total) as usize;
+            let bar = format!("{}{}", "#".repeat(filled), ".".repeat(BAR_WIDTH as usize - filled));
+            eprint!("\r{label} [{bar}] {:>3}% ({done}/{total})", done * 100 / total);
+        },
+        f,
+    );
+    // Erase the bar, in case one was drawn.
+    eprint!("\r\x1b[2K");
+    let _ = io::stderr().flush();
+    result
+}
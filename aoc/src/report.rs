@@ -0,0 +1,197 @@
+//! Writes a Markdown report of every day: its stars, which parts are solved,
+//! and how long they take. Reports of the same state come out the same, so
+//! that diffs of it only show real changes: times are rounded, and a time
+//! from the previous report is kept unless the new one rounds to more than
+//! one step away from it.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Write};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+use runner::{Answers, Budget, Day, PartReport};
+
+/// Solves each day with an input `runs` times, and returns the report, with
+/// the times of `previous` where they still hold. The links to the source
+/// files are relative to the workspace root.
+pub fn generate(year: Option<u16>, embedded: bool, runs: usize, previous: &str, budget: Budget) -> Result<String, Box<dyn Error>> {
+    let previous = previous_cells(previous);
+    let days: Vec<Day> = aoc::days().into_iter()
+        .filter(|day| year.is_none_or(|year| year == day.id.year))
+        .collect();
+
+    let mut out = String::from("# Advent of Code\n");
+    let mut years: Vec<u16> = days.iter().map(|day| day.id.year).collect();
+    years.dedup();
+    for year in years {
+        let mut rows = String::new();
+        let (mut stars, mut parts) = (0, 0);
+        for day in days.iter().filter(|day| day.id.year == year) {
+            let known = Answers::load(day.id)?;
+            let times = median_times(day, input(day, embedded)?.as_deref(), runs, budget);
+            let day_stars = (1..=2).filter(|&part| known.get(part).is_some()).count();
+            stars += day_stars;
+            parts += day.puzzle.parts.len();
+
+            write!(rows, "| [{}]({}) | {} | {} |", day.id, source_path(day), day.puzzle.title, "★".repeat(day_stars))?;
+            for part in 1..=2 {
+                let cell = if day.puzzle.has_part(part) {
+                    let previous = previous.get(&(day.id.to_string(), part)).map(String::as_str);
+                    part_cell(&times, part, known.get(part), previous)
+                } else {
+                    String::from("—")
+                };
+                write!(rows, " {cell} |")?;
+            }
+            rows.push('\n');
+        }
+
+        writeln!(out, "\n## {year}\n")?;
+        writeln!(out, "{stars} stars, {parts} of {} parts implemented.\n", 2 * aoc::DAYS_PER_YEAR)?;
+        out += "| Day | Puzzle | Stars | Part 1 | Part 2 |\n";
+        out += "|-----|--------|-------|--------|--------|\n";
+        out += &rows;
+    }
+    out += "\nTimes are medians, rounded up to 1, 2 or 5 times a power of ten. ✓ means the answer \
+            matches the answers store, ✗ that it doesn't, and ? that there is no answer to check.\n";
+    Ok(out)
+}
+
+pub fn run(year: Option<u16>, embedded: bool, runs: usize, output: Option<&Path>, budget: Budget) -> Result<(), Box<dyn Error>> {
+    let previous = match output {
+        Some(path) if path.exists() => fs::read_to_string(path)?,
+        _ => String::new(),
+    };
+    let report = generate(year, embedded, runs, &previous, budget)?;
+    match output {
+        Some(path) => fs::write(path, report)?,
+        None => print!("{report}"),
+    }
+    Ok(())
+}
+
+fn source_path(day: &Day) -> String {
+    format!("{}/day{:02}/src/lib.rs", day.id.year, day.id.day)
+}
+
+fn input(day: &Day, embedded: bool) -> Result<Option<String>, Box<dyn Error>> {
+    if embedded {
+        return Ok(aoc::embedded_input(day.id).map(String::from));
+    }
+    let path = day.id.input_path();
+    Ok(if path.exists() {Some(fs::read_to_string(path)?)} else {None})
+}
+
+/// What happened when a part was solved: the median time and the answer, or
+/// why there is none.
+type Times = Result<Vec<PartReport>, String>;
+
+fn median_times(day: &Day, input: Option<&str>, runs: usize, budget: Budget) -> Times {
+    let input = input.ok_or("no input")?;
+    let mut reports: Vec<Vec<PartReport>> = vec![];
+    for _ in 0..runs.max(1) {
+        let report = day.run_with_budget(input, budget).map_err(|_| "error")?;
+        for (i, part) in report.parts.into_iter().enumerate() {
+            if i == reports.len() {
+                reports.push(vec![]);
+            }
+            reports[i].push(part);
+        }
+    }
+    Ok(reports.into_iter()
+        .map(|mut runs| {
+            runs.sort_by_key(|part| part.time);
+            runs.swap_remove(runs.len() / 2)
+        })
+        .collect())
+}
+
+/// The part cells of each row of a report, by day and part.
+fn previous_cells(report: &str) -> HashMap<(String, usize), String> {
+    let mut cells = HashMap::new();
+    for line in report.lines() {
+        let Some(row) = line.strip_prefix("| [") else {continue;};
+        let Some((day, _)) = row.split_once(']') else {continue;};
+        let columns: Vec<&str> = row.split('|').map(str::trim).collect();
+        for part in 1..=2 {
+            if let Some(cell) = columns.get(2 + part) {
+                cells.insert((day.to_string(), part), cell.to_string());
+            }
+        }
+    }
+    cells
+}
+
+fn part_cell(times: &Times, part: usize, known: Option<&str>, previous: Option<&str>) -> String {
+    let report = match times {
+        Ok(reports) => reports.get(part - 1),
+        Err(why) => return why.to_string(),
+    };
+    let Some(report) = report else {
+        return String::from("—");
+    };
+    if report.timed_out {
+        return String::from("timed out");
+    }
+    if report.failed {
+        return String::from("failed");
+    }
+    let check = match known {
+        None => "?",
+        Some(known) if known == report.answer => "✓",
+        Some(_) => "✗",
+    };
+    let time = Time::round(report.time);
+    let kept = previous.and_then(|previous| previous.strip_prefix(check)?.trim().parse::<Time>().ok())
+        .filter(|previous| previous.is_near(time));
+    format!("{check} {}", kept.unwrap_or(time))
+}
+
+/// A time as shown in the report.
+#[derive(Clone, Copy)]
+struct Time(Duration);
+
+const UNITS: [(&str, u128); 4] = [("s", 1_000_000_000), ("ms", 1_000_000), ("µs", 1_000), ("ns", 1)];
+
+impl Time {
+    /// Rounds a time up to 1, 2 or 5 times a power of ten, so that the small
+    /// changes from one run to the next don't show.
+    fn round(time: Duration) -> Self {
+        let nanos = time.as_nanos().max(1);
+        let mut scale = 1;
+        loop {
+            if let Some(step) = [1, 2, 5].iter().map(|m| m * scale).find(|&step| nanos <= step) {
+                return Self(Duration::from_nanos(step as u64));
+            }
+            scale *= 10;
+        }
+    }
+
+    /// Whether two rounded times are at most one step apart, e.g. 20 µs is
+    /// near 10 µs and 50 µs, but not 5 µs or 100 µs.
+    fn is_near(self, other: Self) -> bool {
+        self.0 * 2 <= other.0 * 5 && other.0 * 2 <= self.0 * 5
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let nanos = self.0.as_nanos();
+        let (unit, per_unit) = UNITS.into_iter().find(|&(_, per_unit)| nanos >= per_unit).unwrap_or(UNITS[3]);
+        write!(f, "{} {unit}", nanos / per_unit)
+    }
+}
+
+impl FromStr for Time {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, unit) = s.split_once(' ').ok_or(())?;
+        let (_, per_unit) = UNITS.into_iter().find(|&(name, _)| name == unit).ok_or(())?;
+        let nanos = value.parse::<u64>().map_err(|_| ())?.checked_mul(per_unit as u64).ok_or(())?;
+        Ok(Self(Duration::from_nanos(nanos)))
+    }
+}
@@ -0,0 +1,117 @@
+//! Solves a day again whenever one of its input files changes, e.g. while
+//! editing a hand-made grid, and shows which answers changed since the last
+//! time.
+
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fs;
+use std::path::{self, Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use notify::event::{AccessKind, AccessMode};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use runner::{Budget, Day};
+
+/// Editors often save a file in several steps, so changes are only acted on
+/// once there have been none for this long.
+const SETTLE_TIME: Duration = Duration::from_millis(100);
+
+/// An input file, and what it was and gave the last time it was solved.
+struct Watched {
+    path: PathBuf,
+    input: Option<String>,
+    /// The last answer to each part, kept through parts that fail or time
+    /// out, so that the next answer is compared with it.
+    answers: Vec<Option<String>>,
+}
+
+/// Watches the files until the watcher fails, which it only does on errors.
+pub fn run(day: &Day, paths: &[PathBuf], budget: Budget) -> Result<(), Box<dyn Error>> {
+    let mut files: Vec<Watched> = paths.iter()
+        .map(|path| Ok(Watched{path: path::absolute(path)?, input: None, answers: vec![]}))
+        .collect::<Result<_, Box<dyn Error>>>()?;
+
+    // Files are watched through their directories, so that a file that is
+    // saved by replacing it is still watched afterwards.
+    let (events, changes) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(events)?;
+    let dirs: BTreeSet<&Path> = files.iter().filter_map(|file| file.path.parent()).collect();
+    for dir in dirs {
+        watcher.watch(dir, RecursiveMode::NonRecursive)?;
+    }
+
+    for file in &mut files {
+        solve(day, file, budget);
+    }
+    eprintln!("Watching {} for changes", if files.len() == 1 {"the input"} else {"the inputs"});
+
+    loop {
+        let mut changed = BTreeSet::new();
+        let event = changes.recv()?;
+        changed.extend(changed_files(&event?, &files));
+        while let Ok(event) = changes.recv_timeout(SETTLE_TIME) {
+            changed.extend(changed_files(&event?, &files));
+        }
+        for i in changed {
+            solve(day, &mut files[i], budget);
+        }
+    }
+}
+
+/// The indices of the files that `event` changed.
+fn changed_files(event: &Event, files: &[Watched]) -> Vec<usize> {
+    let is_change = matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+            | EventKind::Access(AccessKind::Close(AccessMode::Write)),
+    );
+    if !is_change {
+        return vec![];
+    }
+    (0..files.len()).filter(|&i| event.paths.contains(&files[i].path)).collect()
+}
+
+/// Solves the file if it changed, and prints the answers, with the previous
+/// ones where they differ.
+fn solve(day: &Day, file: &mut Watched, budget: Budget) {
+    let input = match fs::read_to_string(&file.path) {
+        Ok(input) => input,
+        Err(err) => {
+            println!("{}\n  error: {err}", file.path.display());
+            file.input = None;
+            return;
+        }
+    };
+    if file.input.as_ref() == Some(&input) {
+        return;
+    }
+
+    println!("{}", file.path.display());
+    let report = match day.run_with_budget(&input, budget) {
+        Ok(report) => report,
+        Err(err) => {
+            println!("  error: {err}");
+            file.input = Some(input);
+            return;
+        }
+    };
+    file.answers.resize(report.parts.len(), None);
+    for (i, part) in report.parts.iter().enumerate() {
+        if part.timed_out {
+            println!("  Part {} timed out after {:?}", i + 1, part.time);
+            continue;
+        }
+        if part.failed {
+            println!("  Part {} error: {}", i + 1, part.answer);
+            continue;
+        }
+        let was = match &file.answers[i] {
+            Some(previous) if *previous != part.answer => format!(" (was {previous})"),
+            _ => String::new(),
+        };
+        println!("  Part {}: {}{was}  [{:?}]", i + 1, part.answer, part.time);
+        file.answers[i] = Some(part.answer.clone());
+    }
+    file.input = Some(input);
+}
@@ -0,0 +1,22 @@
+use std::fs;
+
+use runner::Answers;
+
+/// Checks every day that has an input in the inputs store against the
+/// answers store. Days without a stored input are skipped.
+#[test]
+fn known_answers() {
+    for day in aoc::days() {
+        let Ok(input) = fs::read_to_string(day.id.input_path()) else {
+            continue;
+        };
+        let known = Answers::load(day.id).unwrap();
+        let report = day.run(&input).unwrap();
+
+        for (i, part) in report.parts.iter().enumerate() {
+            if let Some(expected) = known.get(i + 1) {
+                assert_eq!(part.answer, expected, "{} part {}", day.id, i + 1);
+            }
+        }
+    }
+}
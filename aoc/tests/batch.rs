@@ -0,0 +1,34 @@
+use std::fs;
+use std::process::Command;
+
+/// Runs the test fixture that panics on a 0, and takes a number per line.
+#[test]
+fn batch_reports_each_input() {
+    let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+    let inputs = dir.join("inputs");
+    let expected = dir.join("expected");
+    fs::create_dir_all(&inputs).unwrap();
+    fs::create_dir_all(&expected).unwrap();
+    fs::write(inputs.join("good.txt"), "1\n2\n").unwrap();
+    fs::write(expected.join("good.txt"), "Part 1: 3\n").unwrap();
+    fs::write(inputs.join("malformed.txt"), "1\ntwo\n").unwrap();
+    fs::write(inputs.join("panics.txt"), "1\n0\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["batch", "2000", "1"])
+        .arg(&inputs)
+        .arg("--expected")
+        .arg(&expected)
+        .env("AOC_FIXTURES", "1")
+        .output()
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 3, "{stdout}");
+    assert!(lines[0].starts_with("good.txt       part 1  3 ") && lines[0].ends_with("  ok"), "{stdout}");
+    assert_eq!(lines[1], "malformed.txt  error: invalid digit found in string", "{stdout}");
+    assert_eq!(lines[2], "panics.txt     error: Part 1 panicked: found a 0", "{stdout}");
+}
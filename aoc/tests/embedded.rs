@@ -0,0 +1,20 @@
+use runner::DayId;
+
+#[test]
+fn only_registered_days_can_be_embedded() {
+    assert_eq!(aoc::embedded_input(DayId::new(2024, 26)), None);
+    if !aoc::EMBEDS_INPUTS {
+        assert!(aoc::days().iter().all(|day| aoc::embedded_input(day.id).is_none()));
+    }
+}
+
+#[test]
+#[cfg(not(feature = "embed-inputs"))]
+fn embedded_inputs_need_the_feature() {
+    for args in [&["run", "2024", "1", "--embedded"][..], &["run-all", "--embedded"]] {
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_aoc")).args(args).output().unwrap();
+        assert!(!output.status.success());
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(stderr.contains("build it with `--features embed-inputs`"), "{stderr}");
+    }
+}
@@ -0,0 +1,32 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use serde_json::Value;
+
+#[test]
+fn json_trace_events_come_from_the_day() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["--explain", "--trace-format", "json", "run", "2024", "7"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // The second equation can't be made true, which is explained.
+    child.stdin.take().unwrap().write_all(b"190: 10 19\n83: 17 5\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "Part 1: 190\nPart 2: 190\n");
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let events: Vec<Value> = stderr.lines()
+        .filter(|line| !line.starts_with("Parsed in ") && !line.starts_with("Part "))
+        .map(|line| serde_json::from_str(line).unwrap_or_else(|err| panic!("{err}: {line}")))
+        .collect();
+    assert_eq!(events.len(), 2, "{stderr}");
+    for event in &events {
+        assert_eq!(event["target"], "aoc2024_day07", "{event}");
+        assert_eq!(event["fields"]["message"], "equation cannot be made true", "{event}");
+        assert_eq!(event["spans"][0]["day"], "2024/07", "{event}");
+    }
+}
@@ -0,0 +1,35 @@
+use runner::{Budget, DayId, Report};
+
+fn report(year: u16, day: u8, input: &str) -> Report {
+    let day = aoc::find(DayId::new(year, day)).unwrap();
+    day.run_with_budget(input, Budget::steps(10_000)).unwrap()
+}
+
+#[test]
+fn stone_too_big_to_multiply() {
+    let report = report(2024, 11, "340282366920938463463374607431768211455");
+    assert!(report.parts.iter().all(|part| part.failed));
+    assert_eq!(report.parts[0].answer, "Stone 340282366920938463463374607431768211455 is too big to multiply by 2024");
+}
+
+#[test]
+fn robots_that_never_form_a_tree() {
+    let report = report(2024, 14, "p=0,0 v=0,0\np=2,2 v=0,0\n");
+    assert!(!report.parts[0].failed);
+    assert!(report.parts[1].failed);
+    assert_eq!(report.parts[1].answer, "No Christmas tree found");
+}
+
+#[test]
+fn timed_out_parts_have_not_failed() {
+    let report = report(2024, 17, "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0\n");
+    assert!(report.parts[0].timed_out);
+    assert!(!report.parts[0].failed);
+}
+
+#[test]
+fn failed_answers_are_not_saved() {
+    let report = report(2024, 14, "p=0,0 v=0,0\n");
+    assert_eq!(report.answers().get(1), Some("0"));
+    assert_eq!(report.answers().get(2), None);
+}
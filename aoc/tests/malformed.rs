@@ -0,0 +1,94 @@
+use runner::{Budget, DayId};
+
+fn answers(day: u8, input: &str) -> Vec<String> {
+    let day = aoc::find(DayId::new(2024, day)).unwrap();
+    let report = day.run_with_budget(input, Budget::steps(100_000)).unwrap();
+    report.parts.into_iter().map(|part| part.answer).collect()
+}
+
+fn error(day: u8, input: &str) -> String {
+    let day = aoc::find(DayId::new(2024, day)).unwrap();
+    day.run(input).unwrap_err().to_string()
+}
+
+#[test]
+fn day04_ragged_rows() {
+    assert_eq!(answers(4, "X\nMM\nAAA\nSSSS\n"), ["2", "0"]);
+    assert_eq!(answers(4, "XMAS\n\nM.S\n.A\nM.S\n"), ["1", "0"]);
+}
+
+#[test]
+fn day11_stone_too_big() {
+    let stone = format!("1{}", "0".repeat(36));
+    let message = format!("Stone {stone} is too big to multiply by 2024");
+    assert_eq!(answers(11, &stone), [message.clone(), message]);
+}
+
+#[test]
+fn day13_numbers_too_big() {
+    let input = "Button A: X+99999999999999999999, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n";
+    assert_eq!(error(13, input), "number too large to fit in target type");
+}
+
+#[test]
+fn day14_numbers_too_big() {
+    assert_eq!(error(14, "p=0,99999999999999999999 v=1,1\n"), "number too large to fit in target type");
+    let input = "p=-9223372036854775808,9223372036854775807 v=9223372036854775807,-9223372036854775808\n";
+    assert_eq!(answers(14, input)[0], "0");
+}
+
+#[test]
+fn day15_invalid_warehouses() {
+    let moves = "\n\n<^>v\n";
+    assert_eq!(error(15, &format!("####\n#.@.\n####{moves}")), "The warehouse must be surrounded by walls");
+    assert_eq!(error(15, &format!("####\n#@.#\n###{moves}")), "Rows of the warehouse have different lengths");
+    assert_eq!(error(15, &format!("####\n#@[#\n####{moves}")), "Unknown tile '[' in the warehouse");
+    assert_eq!(error(15, "####\n#..#\n####\n\n<\n"), "No robot location found in input");
+    assert_eq!(answers(15, &format!("#####\n#@O.#\n#####{moves}")), ["103", "104"]);
+}
+
+#[test]
+fn day16_end_unreachable() {
+    assert_eq!(answers(16, "#####\n#S#E#\n#####\n"), ["No answer found"]);
+    assert_eq!(answers(16, "S.\n.\n.E\n"), ["2003"]);
+}
+
+#[test]
+fn day17_invalid_instructions() {
+    let program = |program: &str| format!("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: {program}\n");
+    assert_eq!(error(17, &program("0,7")), "Invalid combo operand 7 at 0");
+    assert_eq!(error(17, &program("1,2,0")), "The instruction at 2 has no operand");
+    assert_eq!(error(17, &program("1,2,8,0")), "Program values must be 3-bit numbers");
+    // The jump lands in the middle of an instruction, which is checked too.
+    assert_eq!(error(17, &program("3,3,1,7,7,1")), "Invalid combo operand 7 at 3");
+    assert_eq!(error(17, "Register A: -1\nRegister B: 0\nRegister C: 0\n\nProgram: 5,4\n"), "invalid digit found in string");
+}
+
+#[test]
+fn day17_large_shifts() {
+    let input = "Register A: 729\nRegister B: 64\nRegister C: 0\n\nProgram: 0,5,5,4\n";
+    assert_eq!(answers(17, input), ["0"]);
+}
+
+#[test]
+fn day18_bytes_outside_memory() {
+    assert_eq!(error(18, "5,4\n71,0\n"), "Byte 71,0 is outside the memory space");
+}
+
+#[test]
+fn day18_exit_cut_off() {
+    assert_eq!(answers(18, "1,0\n0,1\n"), ["No answer found", "0,1"]);
+}
+
+#[test]
+fn day20_end_unreachable() {
+    assert_eq!(answers(20, "S#E\n"), ["No answer found"]);
+}
+
+#[test]
+fn day20_start_away_from_the_corner() {
+    // Cutting through the wall near the left end saves at least 100
+    // picoseconds, for the first 10 walls.
+    let input = format!("\nS{}\n{}.\nE{}\n", ".".repeat(59), "#".repeat(59), ".".repeat(59));
+    assert_eq!(answers(20, &input), ["10"]);
+}
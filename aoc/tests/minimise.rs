@@ -0,0 +1,75 @@
+use std::error::Error;
+
+use aoc::minimise::{self, Failure};
+use runner::{AnswerKind, Budget, Day, DayId, InputShape, Puzzle, Solution};
+
+/// Panics if an `X` is anywhere above a `Y` in the same column.
+struct Columns;
+impl Solution for Columns {
+    const ID: DayId = DayId::new(2000, 1);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Columns",
+        parts: &[AnswerKind::Integer],
+        parameters: &[],
+        input: InputShape::Grid,
+    };
+    type Input<'a> = Vec<&'a [u8]>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        Ok(input.lines().map(str::as_bytes).collect())
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        let find = |c| input.iter().enumerate().find_map(|(i, row)| Some((i, row.iter().position(|&x| x == c)?)));
+        if let (Some(x), Some(y)) = (find(b'X'), find(b'Y')) {
+            assert!(x.1 != y.1 || x.0 > y.0, "X above Y");
+        }
+        input.len().to_string()
+    }
+}
+
+fn find(day: u8) -> Day {
+    aoc::find(DayId::new(2024, day)).unwrap()
+}
+
+#[test]
+fn grid_rows_and_columns_are_removed() {
+    let input = "..X..\n.....\n#....\n...#.\n..Y..\n";
+    let minimised = minimise::minimise(&Day::of::<Columns>(), input, Failure::Panic, Budget::UNLIMITED).unwrap();
+    assert_eq!(minimised.input, "X\nY\n");
+    assert_eq!(minimised.failure, "Part 1 panicked: X above Y");
+}
+
+#[test]
+fn blocks_are_kept_whole() {
+    let machine = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n";
+    let broken = "Button A: X+99999999999999999999, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n";
+    let input = [machine, broken, machine, machine].join("\n");
+    let minimised = minimise::minimise(&find(13), &input, Failure::Error, Budget::UNLIMITED).unwrap();
+    assert_eq!(minimised.input, broken);
+    assert_eq!(minimised.failure, "number too large to fit in target type");
+}
+
+#[test]
+fn sections_are_kept() {
+    let input = "########\n#..O...#\n#.@..[.#\n#..O...#\n########\n\n<^^>>>vv<v>>v<<\nvvv<<^^\n";
+    let minimised = minimise::minimise(&find(15), input, Failure::Error, Budget::UNLIMITED).unwrap();
+    assert_eq!(minimised.failure, "Unknown tile '[' in the warehouse");
+    let (warehouse, moves) = minimised.input.split_once("\n\n").unwrap();
+    assert!(warehouse.contains('[') && warehouse.len() < 30, "{warehouse}");
+    assert_eq!(moves.trim().len(), 1);
+}
+
+#[test]
+fn items_are_removed_from_lines() {
+    let input = "1 2 3\n4 5 x 6\n7 8 9\n";
+    let minimised = minimise::minimise(&find(2), input, Failure::Error, Budget::UNLIMITED).unwrap();
+    assert_eq!(minimised.input, "x\n");
+}
+
+#[test]
+fn inputs_must_fail_to_begin_with() {
+    let input = "1 2 3\n4 5 6\n";
+    let err = minimise::minimise(&find(2), input, Failure::Error, Budget::UNLIMITED).unwrap_err();
+    assert_eq!(err.to_string(), "2024/02 doesn't fail with an error on this input");
+}
@@ -0,0 +1,108 @@
+use std::error::Error;
+
+use runner::{AnswerKind, Day, DayId, InputShape, Puzzle, Solution};
+
+fn answers(year: u16, day: u8, input: &str) -> Vec<String> {
+    let day = aoc::find(DayId::new(year, day)).unwrap();
+    let report = day.run(input).unwrap();
+    report.parts.into_iter().map(|part| part.answer).collect()
+}
+
+#[test]
+fn day01_distances_beyond_i32() {
+    let input = "2000000000   -2000000000\n2000000000   2000000000\n";
+    assert_eq!(answers(2024, 1, input), ["4000000000", "4000000000"]);
+}
+
+#[test]
+fn day02_differences_beyond_i32() {
+    let input = "2147483647 -2147483648\n-2147483648 2147483647 2147483646\n";
+    assert_eq!(answers(2024, 2, input), ["0", "2"]);
+}
+
+#[test]
+fn day07_more_operators_than_flags() {
+    let input = format!("7: 5{} 7\n", " 0".repeat(128));
+    assert_eq!(answers(2024, 7, &input), ["7", "7"]);
+}
+
+#[test]
+fn day07_values_beyond_u64() {
+    let input = "18446744073709551615: 1844674407370955161 5\n\
+                 18446744073709551615: 18446744073709551610 5\n\
+                 18446744073709551615: 99999999999 99999999999\n\
+                 5: 99999999999 99999999999 0 5\n";
+    assert_eq!(answers(2024, 7, input), ["18446744073709551620", "36893488147419103235"]);
+}
+
+#[test]
+fn day07_part_2_allows_everything_part_1_does() {
+    let input = "5: 99999999999 99999999999 0 5\n\
+                 190: 10 19\n\
+                 7: 18446744073709551615 2 0 7\n\
+                 0: 18446744073709551615 18446744073709551615 0\n";
+    let answers: Vec<u128> = answers(2024, 7, input).iter().map(|answer| answer.parse().unwrap()).collect();
+    assert_eq!(answers[0], 202);
+    assert!(answers[1] >= answers[0], "{answers:?}");
+}
+
+#[test]
+fn day11_stones_beyond_u64() {
+    assert_eq!(answers(2024, 11, "1000000000000000000000"), ["24222", "29259893612811"]);
+}
+
+#[test]
+fn day13_prizes_beyond_i64_products() {
+    let input = "Button A: X+94, Y+34\nButton B: X+22, Y+67\n\
+                 Prize: X=8400000000000000000, Y=5400000000000000000\n";
+    assert_eq!(answers(2024, 13, input), ["280000000000000000", "0"]);
+}
+
+#[test]
+fn day13_prizes_moved_beyond_i64() {
+    let input = "Button A: X+1, Y+0\nButton B: X+0, Y+1\n\
+                 Prize: X=9223372036854775807, Y=9223372036854775807\n";
+    assert_eq!(answers(2024, 13, input), ["36893488147419103228", "36893528147419103228"]);
+}
+
+#[test]
+fn day19_arrangements_beyond_u64() {
+    let input = format!("a, aa\n\n{}\n", "a".repeat(100));
+    assert_eq!(answers(2024, 19, &input), ["1", "573147844013817084101"]);
+}
+
+#[test]
+fn day03_sums_beyond_i32() {
+    let input = "mul(999,999)".repeat(3000);
+    assert_eq!(answers(2024, 3, &input), ["2994003000", "2994003000"]);
+}
+
+/// Adds up the bytes of the input in a `u8`.
+struct ByteSum;
+impl Solution for ByteSum {
+    const ID: DayId = DayId::new(2000, 1);
+    const PUZZLE: Puzzle = Puzzle{
+        title: "Byte Sum",
+        parts: &[AnswerKind::Integer],
+        parameters: &[],
+        input: InputShape::Lines,
+    };
+    type Input<'a> = &'a [u8];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>> {
+        Ok(input.as_bytes())
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> String {
+        input.iter().sum::<u8>().to_string()
+    }
+}
+
+/// Solvers without a wide fallback report overflow as an error when built
+/// with overflow checks, as in the `checked` profile.
+#[test]
+#[cfg_attr(not(debug_assertions), ignore = "needs overflow checks")]
+fn overflow_is_reported() {
+    let err = Day::of::<ByteSum>().run("zzz").unwrap_err();
+    assert_eq!(err.to_string(), "Part 1 panicked: attempt to add with overflow");
+}
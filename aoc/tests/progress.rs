@@ -0,0 +1,55 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use runner::{progress, Budget, DayId};
+
+/// Runs a day, returning every progress report its solvers made.
+fn reports(year: u16, day: u8, input: &str, budget: Budget) -> Vec<(u64, u64)> {
+    let day = aoc::find(DayId::new(year, day)).unwrap();
+    let reports = Rc::new(RefCell::new(vec![]));
+    let recorded = Rc::clone(&reports);
+    progress::with_reporter(
+        move |done, total| recorded.borrow_mut().push((done, total)),
+        || day.run_with_budget(input, budget).unwrap(),
+    );
+    reports.take()
+}
+
+#[test]
+fn obstacle_candidates_are_counted_up_to_the_total() {
+    let input = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+    let reports = reports(2024, 6, input, Budget::UNLIMITED);
+    assert!(!reports.is_empty());
+    assert!(reports.windows(2).all(|w| w[0].0 < w[1].0 && w[0].1 == w[1].1));
+    // The first candidate is the guard's starting cell, which isn't checked.
+    assert_eq!(reports.first(), Some(&(2, 41)));
+    assert_eq!(reports.last(), Some(&(41, 41)));
+}
+
+#[test]
+fn time_steps_are_reported_out_of_the_period() {
+    let input = "p=0,0 v=1,1\np=2,2 v=1,1\n";
+    // One step of the budget goes to finding each robot's period.
+    let reports = reports(2024, 14, input, Budget::steps(2 + 100));
+    assert_eq!(reports.len(), 100);
+    assert!(reports.iter().enumerate().all(|(t, &report)| report == (t as u64 + 1, 101 * 103)));
+}
+
+#[test]
+fn nothing_is_reported_without_a_reporter() {
+    // This must not panic or print anything.
+    progress::report(1, 2);
+    let day = aoc::find(DayId::new(2024, 6)).unwrap();
+    day.run(".^.\n").unwrap();
+}
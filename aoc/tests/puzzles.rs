@@ -0,0 +1,78 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use aoc2024::*;
+use runner::{AnswerKind, Budget, DayId, Solution};
+
+#[test]
+fn every_day_has_a_title_and_parts() {
+    let days = aoc::days();
+    assert!(days.windows(2).all(|w| w[0].id < w[1].id), "days are sorted and unique");
+    for day in days {
+        assert!(!day.puzzle.title.is_empty(), "{}", day.id);
+        assert!((1..=2).contains(&day.puzzle.parts.len()), "{}", day.id);
+    }
+}
+
+/// Whether `S` solves part 2 of the example in its fuzzing corpus.
+fn solves_part_2<S: Solution>() -> bool {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../fuzz/corpus/day{:02}/example", S::ID.day));
+    let input = fs::read_to_string(path).unwrap();
+    let parsed = S::parse(&input).unwrap();
+    runner::cancel::with_budget(Budget::steps(10_000), || S::part_2(&parsed)).0.is_some()
+}
+
+#[test]
+fn parts_match_the_solvers() {
+    fn check<S: Solution>() {
+        assert_eq!(S::PUZZLE.parts.len() == 2, solves_part_2::<S>(), "{}", S::ID);
+    }
+    check::<day01::Day01>();
+    check::<day02::Day02>();
+    check::<day03::Day03>();
+    check::<day04::Day04>();
+    check::<day05::Day05>();
+    check::<day06::Day06>();
+    check::<day07::Day07>();
+    check::<day08::Day08>();
+    check::<day09::Day09>();
+    check::<day10::Day10>();
+    check::<day11::Day11>();
+    check::<day12::Day12>();
+    check::<day13::Day13>();
+    check::<day14::Day14>();
+    check::<day15::Day15>();
+    check::<day16::Day16>();
+    check::<day17::Day17>();
+    check::<day18::Day18>();
+    check::<day19::Day19>();
+    check::<day20::Day20>();
+}
+
+#[test]
+fn unimplemented_parts_are_not_run() {
+    let day = aoc::find(DayId::new(2024, 17)).unwrap();
+    // The input isn't even parsed.
+    let (_, report) = day.run_part("not a program", 2, Budget::UNLIMITED).unwrap();
+    assert!(report.is_none());
+    assert!(day.run_part("not a program", 3, Budget::UNLIMITED).unwrap().1.is_none());
+
+    let input = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n";
+    let report = day.run(input).unwrap();
+    assert_eq!(report.parts.len(), day.puzzle.parts.len());
+    assert_eq!(day.puzzle.parts, [AnswerKind::Text]);
+    assert_eq!(report.parts[0].answer, "4,6,3,5,6,3,5,2,1,0");
+}
+
+#[test]
+fn list_shows_a_calendar() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc")).args(["list", "--year", "2024"]).output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut lines = stdout.lines();
+    assert!(lines.next().unwrap().trim().starts_with("1  2  3"));
+    assert_eq!(lines.next().unwrap(), "2024 ** ** ** ** ** ** ** ** ** ** ** ** ** ** **  *  * ** **  *  .  .  .  .  .");
+    assert!(stdout.lines().any(|line| line.starts_with("2024/01  Historian Hysteria")));
+    assert!(stdout.lines().any(|line| line.ends_with("size=71 bytes=1024")));
+}
@@ -0,0 +1,31 @@
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn report_checks_answers_and_links_sources() {
+    let dir = std::env::temp_dir().join(format!("aoc-report-{}", std::process::id()));
+    fs::create_dir_all(dir.join("inputs/2024")).unwrap();
+    fs::create_dir_all(dir.join("answers/2024")).unwrap();
+    fs::write(dir.join("inputs/2024/day01.txt"), "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n").unwrap();
+    fs::write(dir.join("answers/2024/day01.txt"), "Part 1: 11\nPart 2: 99\n").unwrap();
+
+    // A time that is far from the new one isn't kept.
+    let path = dir.join("REPORT.md");
+    fs::write(&path, "| [2024/01](2024/day01/src/lib.rs) | Historian Hysteria | ★★ | ✓ 100 s | ✗ 100 s |\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["report", "--year", "2024", "--runs", "3", "--output"])
+        .arg(&path)
+        .env("AOC_DATA_DIR", &dir)
+        .output()
+        .unwrap();
+    let report = fs::read_to_string(&path).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(output.status.success());
+    assert!(!report.contains("100 s"), "{report}");
+
+    assert!(report.contains("\n## 2024\n\n2 stars, 37 of 50 parts implemented.\n"), "{report}");
+    let day01 = report.lines().find(|line| line.starts_with("| [2024/01]")).unwrap();
+    assert!(day01.starts_with("| [2024/01](2024/day01/src/lib.rs) | Historian Hysteria | ★★ | ✓ "), "{day01}");
+    assert!(day01.contains("| ✗ "), "{day01}");
+    assert!(report.contains("| [2024/17](2024/day17/src/lib.rs) | Chronospatial Computer |  | no input | — |\n"), "{report}");
+}
@@ -0,0 +1,124 @@
+use std::io::BufReader;
+
+use runner::DayId;
+
+/// Buffer sizes to read with, so that lines and instructions get split
+/// between reads in every possible place.
+const CAPACITIES: [usize; 5] = [1, 2, 5, 13, 8192];
+
+/// Checks that streaming gives the same answers as the in-memory solvers.
+fn assert_same_answers(day: u8, input: &str) {
+    let id = DayId::new(2024, day);
+    let expected = aoc::find(id).unwrap().run(input).unwrap().answers();
+    for capacity in CAPACITIES {
+        let reader = BufReader::with_capacity(capacity, input.as_bytes());
+        let answers = aoc::solve_stream(id, reader).unwrap().unwrap();
+        assert_eq!(answers, expected, "{id} read {capacity} bytes at a time");
+    }
+}
+
+/// A small deterministic generator, so that the inputs are the same on every
+/// run.
+struct Lcg(u64);
+
+impl Lcg {
+    fn below(&mut self, n: u64) -> u64 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (self.0 >> 33) % n
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.below(items.len() as u64) as usize]
+    }
+}
+
+#[test]
+fn day01() {
+    assert_same_answers(1, "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n");
+
+    let mut rng = Lcg(1);
+    let input: String = (0..500)
+        .map(|_| format!("{}   {}\n", rng.below(50), rng.below(50)))
+        .collect();
+    assert_same_answers(1, &input);
+}
+
+#[test]
+fn day02() {
+    assert_same_answers(2, "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n");
+
+    let mut rng = Lcg(2);
+    let input: String = (0..500)
+        .map(|_| {
+            let mut level = rng.below(20) as i64;
+            let len = 1 + rng.below(8);
+            let levels: Vec<String> = (0..len)
+                .map(|_| {
+                    level += rng.below(9) as i64 - 4;
+                    level.to_string()
+                })
+                .collect();
+            levels.join(" ") + "\n"
+        })
+        .collect();
+    assert_same_answers(2, &input);
+}
+
+#[test]
+fn day03() {
+    assert_same_answers(3, "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))");
+
+    let mut rng = Lcg(3);
+    let pieces = ["mul(", "12", "3", ",", ")", "do()", "don't()", "mul(4,5)", "mul(123,456)", "x", "\n", "é"];
+    let input: String = (0..5000).map(|_| rng.pick(&pieces)).collect();
+    assert_same_answers(3, &input);
+}
+
+#[test]
+fn day07() {
+    assert_same_answers(7, "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+");
+
+    let mut rng = Lcg(7);
+    let input: String = (0..300)
+        .map(|_| {
+            let operands: Vec<u64> = (0..1 + rng.below(5)).map(|_| 1 + rng.below(20)).collect();
+            let target: u64 = if rng.below(2) == 0 {operands.iter().sum()} else {rng.below(1000)};
+            let operands: Vec<String> = operands.iter().map(u64::to_string).collect();
+            format!("{target}: {}\n", operands.join(" "))
+        })
+        .collect();
+    assert_same_answers(7, &input);
+}
+
+#[test]
+fn day09() {
+    assert_same_answers(9, "2333133121414131402\n");
+    assert_same_answers(9, "12345");
+
+    let mut rng = Lcg(9);
+    let input: String = (0..2001).map(|_| char::from(b'0' + rng.below(10) as u8)).collect();
+    assert_same_answers(9, &input);
+}
+
+#[test]
+fn invalid_input_is_an_error() {
+    let id = DayId::new(2024, 9);
+    assert!(aoc::solve_stream(id, "12a".as_bytes()).unwrap().is_err());
+    assert!(aoc::solve_stream(id, "1 2".as_bytes()).unwrap().is_err());
+    assert!(aoc::solve_stream(DayId::new(2024, 1), "1 x\n".as_bytes()).unwrap().is_err());
+}
+
+#[test]
+fn other_days_cannot_be_streamed() {
+    assert!(aoc::solve_stream(DayId::new(2024, 6), "".as_bytes()).is_none());
+}
@@ -0,0 +1,44 @@
+use runner::{Budget, DayId};
+
+/// Runs a day with a small step budget, returning which parts timed out.
+fn timed_out_parts(year: u16, day: u8, input: &str) -> Vec<bool> {
+    let day = aoc::find(DayId::new(year, day)).unwrap();
+    let report = day.run_with_budget(input, Budget::steps(10_000)).unwrap();
+    report.parts.iter().map(|part| part.timed_out).collect()
+}
+
+#[test]
+fn guard_walking_in_a_loop() {
+    let input = ".#..\n.^.#\n#...\n..#.\n";
+    assert_eq!(timed_out_parts(2024, 6, input), [true, true]);
+}
+
+#[test]
+fn robots_with_a_long_period() {
+    let input = "p=0,0 v=1,1\np=2,2 v=1,1\np=4,4 v=1,1\n";
+    assert_eq!(timed_out_parts(2024, 14, input), [false, true]);
+}
+
+#[test]
+fn robots_that_never_form_a_tree() {
+    let input = "p=0,0 v=0,0\np=2,2 v=0,0\np=4,4 v=0,0\n";
+    let day = aoc::find(DayId::new(2024, 14)).unwrap();
+    let report = day.run_with_budget(input, Budget::steps(10)).unwrap();
+    assert!(!report.parts[1].timed_out);
+    assert_eq!(report.parts[1].answer, "No Christmas tree found");
+}
+
+#[test]
+fn program_that_never_halts() {
+    let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0\n";
+    assert_eq!(timed_out_parts(2024, 17, input), [true]);
+}
+
+#[test]
+fn timed_out_answers_are_not_saved() {
+    let input = "p=0,0 v=1,1\n";
+    let day = aoc::find(DayId::new(2024, 14)).unwrap();
+    let report = day.run_with_budget(input, Budget::steps(10)).unwrap();
+    assert_eq!(report.answers().get(1), Some("0"));
+    assert_eq!(report.answers().get(2), None);
+}
@@ -0,0 +1,111 @@
+use runner::{Budget, DayId, DEFAULT_VARIANT};
+
+/// Checks that every variant of a day gives the same answers as the default.
+fn assert_variants_agree(day: u8, input: &str) {
+    let day = aoc::find(DayId::new(2024, day)).unwrap();
+    let differences = aoc::cross_check(&day, input, Budget::UNLIMITED).unwrap();
+    assert!(differences.is_empty(), "{differences:#?}\n{input}");
+}
+
+/// A small deterministic generator, so that the inputs are the same on every
+/// run.
+struct Lcg(u64);
+
+impl Lcg {
+    fn below(&mut self, n: u64) -> u64 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (self.0 >> 33) % n
+    }
+}
+
+#[test]
+fn variants_are_listed_after_the_default() {
+    let day = aoc::find(DayId::new(2024, 2)).unwrap();
+    assert_eq!(day.variants(1), [DEFAULT_VARIANT]);
+    assert_eq!(day.variants(2), [DEFAULT_VARIANT, "brute-force"]);
+    assert!(day.variants(3).is_empty());
+    assert!(day.run_variant("1 2\n", 1, "brute-force", Budget::UNLIMITED).unwrap().1.is_none());
+
+    let report = day.run_with_variant("1 2\n1 5 6\n", "brute-force", Budget::UNLIMITED).unwrap();
+    assert_eq!(report.answers().parts, [Some(String::from("1")), Some(String::from("2"))]);
+    let err = day.run_with_variant("1 2\n", "fast", Budget::UNLIMITED).unwrap_err();
+    assert_eq!(err.to_string(), "2024/02 has no variant \"fast\"");
+}
+
+#[test]
+fn day02() {
+    let mut rng = Lcg(2);
+    let input: String = (0..2000)
+        .map(|_| {
+            let mut level = rng.below(20) as i64;
+            let levels: Vec<String> = (0..rng.below(9))
+                .map(|_| {
+                    level += rng.below(9) as i64 - 4;
+                    level.to_string()
+                })
+                .collect();
+            levels.join(" ") + "\n"
+        })
+        .collect();
+    assert_variants_agree(2, &input);
+}
+
+#[test]
+fn day07() {
+    let mut rng = Lcg(7);
+    let input: String = (0..1000)
+        .map(|_| {
+            let operands: Vec<u64> = (0..1 + rng.below(6)).map(|_| rng.below(12)).collect();
+            let target: u64 = match rng.below(3) {
+                0 => operands.iter().sum(),
+                1 => operands.iter().product(),
+                _ => rng.below(200),
+            };
+            let operands: Vec<String> = operands.iter().map(u64::to_string).collect();
+            format!("{target}: {}\n", operands.join(" "))
+        })
+        .collect();
+    assert_variants_agree(7, &input);
+    assert_variants_agree(7, "0: 99999999999 99999999999 0\n");
+}
+
+#[test]
+fn day18() {
+    let mut rng = Lcg(18);
+    for bytes in [0, 1, 500, 2500, 5000] {
+        let mut input: String = (0..bytes)
+            .map(|_| format!("{},{}\n", rng.below(71), rng.below(71)))
+            .collect();
+        assert_variants_agree(18, &input);
+        input.insert_str(0, "0,0\n");
+        assert_variants_agree(18, &input);
+    }
+    // A wall that is only completed by its last byte, after a byte that
+    // falls again where one already has.
+    let wall: String = (0..71).map(|x| format!("{x},5\n")).chain(["3,5\n".to_string()]).collect();
+    assert_variants_agree(18, &wall);
+}
+
+#[test]
+fn day20() {
+    // A winding corridor, which shortcuts through the walls between its
+    // turns save a lot of, with some walls knocked down at random.
+    let mut rng = Lcg(20);
+    for _ in 0..20 {
+        let (width, height) = (50 + rng.below(40) as usize, 5 + 2 * rng.below(10) as usize);
+        let mut map: Vec<Vec<u8>> = (0..height)
+            .map(|i| {
+                let gap = if i % 4 == 1 {width - 1} else {0};
+                (0..width).map(|j| if i % 2 == 0 || j == gap {b'.'} else {b'#'}).collect()
+            })
+            .collect();
+        for _ in 0..rng.below(6) {
+            let (i, j) = (rng.below(height as u64) as usize, rng.below(width as u64) as usize);
+            map[i][j] = b'.';
+        }
+        map[0][0] = b'S';
+        map[height - 1][rng.below(width as u64) as usize] = b'E';
+        let input: String = map.iter().map(|row| String::from_utf8_lossy(row) + "\n").collect();
+        assert_variants_agree(20, &input);
+    }
+}
@@ -0,0 +1,73 @@
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Watches `path` with the given day, returning the process and the lines
+/// it prints.
+fn watch(day: &str, path: &Path) -> (Child, mpsc::Receiver<String>) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["watch", "2024", day, "--input"])
+        .arg(path)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    let (lines, received) = mpsc::channel();
+    let stdout = BufReader::new(child.stdout.take().unwrap());
+    thread::spawn(move || stdout.lines().map_while(Result::ok).try_for_each(|line| lines.send(line)));
+    (child, received)
+}
+
+#[test]
+fn changed_answers_are_shown() {
+    let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("input.txt");
+    fs::write(&path, "3   4\n4   3\n").unwrap();
+
+    let (mut child, received) = watch("1", &path);
+    let next_line = || received.recv_timeout(Duration::from_secs(10)).unwrap();
+    // Leaves out the time each part took.
+    let next_answer = || next_line().split("  [").next().unwrap().to_string();
+
+    // The files are watched before they are first solved.
+    assert_eq!(next_line(), path.display().to_string());
+    assert_eq!([next_answer(), next_answer()], ["  Part 1: 0", "  Part 2: 7"]);
+    fs::write(&path, "3   4\n4   3\n2   5\n").unwrap();
+    assert_eq!(next_line(), path.display().to_string());
+    assert_eq!([next_answer(), next_answer()], ["  Part 1: 3 (was 0)", "  Part 2: 7"]);
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn failed_parts_are_shown_as_errors() {
+    let dir = std::env::temp_dir().join(format!("aoc-watch-failed-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("input.txt");
+    fs::write(&path, "S.E\n").unwrap();
+
+    let (mut child, received) = watch("16", &path);
+    let next_line = || received.recv_timeout(Duration::from_secs(10)).unwrap();
+    let next_answer = || next_line().split("  [").next().unwrap().to_string();
+
+    assert_eq!(next_line(), path.display().to_string());
+    assert_eq!(next_answer(), "  Part 1: 2");
+    fs::write(&path, "S#E\n").unwrap();
+    assert_eq!(next_line(), path.display().to_string());
+    assert_eq!(next_line(), "  Part 1 error: No answer found");
+    // The answer is compared with the last one, not with the error.
+    fs::write(&path, "S..E\n").unwrap();
+    assert_eq!(next_line(), path.display().to_string());
+    assert_eq!(next_answer(), "  Part 1: 3 (was 2)");
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+}
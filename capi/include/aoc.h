@@ -0,0 +1,73 @@
+/* Generated by cbindgen from capi/src/lib.rs; do not edit. */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The result of [`aoc_solve`]. Everything except `AOC_STATUS_OK` comes with
+ * an error message in place of the answer.
+ */
+typedef enum AocStatus {
+  AOC_STATUS_OK = 0,
+  /**
+   * There is no solution for that day.
+   */
+  AOC_STATUS_NO_SOLUTION = 1,
+  /**
+   * That part of the day has not been solved.
+   */
+  AOC_STATUS_NO_PART = 2,
+  /**
+   * The input is not valid UTF-8, or the solver could not parse it.
+   */
+  AOC_STATUS_INVALID_INPUT = 3,
+  /**
+   * The solver panicked.
+   */
+  AOC_STATUS_PANIC = 4,
+  /**
+   * `answer` was NULL, or `input` was NULL with a nonzero length.
+   */
+  AOC_STATUS_NULL_POINTER = 5,
+  /**
+   * The solver could not find an answer for the input.
+   */
+  AOC_STATUS_NO_ANSWER = 6,
+} AocStatus;
+
+/**
+ * Solves one part of a day's puzzle.
+ *
+ * On return, `*answer` points to a NUL-terminated string holding the answer,
+ * or an error message if the status isn't `AOC_STATUS_OK`. It is owned by the
+ * caller, and must be released with [`aoc_free_string`].
+ *
+ * # Safety
+ *
+ * `input` must point to `input_len` readable bytes (it may be NULL if
+ * `input_len` is 0), and `answer` must be NULL or point to writable memory.
+ */
+enum AocStatus aoc_solve(uint16_t year,
+                         uint8_t day,
+                         uint8_t part,
+                         const uint8_t *input,
+                         size_t input_len,
+                         char **answer);
+
+/**
+ * Releases a string returned by [`aoc_solve`]. Does nothing if `s` is NULL.
+ *
+ * # Safety
+ *
+ * `s` must be NULL or a string returned by this library that hasn't already
+ * been freed.
+ */
+void aoc_free_string(char *s);
+
+#endif  /* AOC_H */
@@ -0,0 +1,107 @@
+//! C ABI for the solvers, built as `libaoc_capi.so`. The matching header,
+//! `include/aoc.h`, is regenerated by the build script.
+//!
+//! ```c
+//! char *answer;
+//! AocStatus status = aoc_solve(2024, 1, 1, input, input_len, &answer);
+//! if (status == AOC_STATUS_OK) {
+//!     printf("%s\n", answer);
+//! }
+//! aoc_free_string(answer);
+//! ```
+
+use std::ffi::{c_char, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+
+use runner::{Budget, DayId, Panicked};
+
+/// The result of [`aoc_solve`]. Everything except `AOC_STATUS_OK` comes with
+/// an error message in place of the answer.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AocStatus {
+    Ok = 0,
+    /// There is no solution for that day.
+    NoSolution = 1,
+    /// That part of the day has not been solved.
+    NoPart = 2,
+    /// The input is not valid UTF-8, or the solver could not parse it.
+    InvalidInput = 3,
+    /// The solver panicked.
+    Panic = 4,
+    /// `answer` was NULL, or `input` was NULL with a nonzero length.
+    NullPointer = 5,
+    /// The solver could not find an answer for the input.
+    NoAnswer = 6,
+}
+
+/// Solves one part of a day's puzzle.
+///
+/// On return, `*answer` points to a NUL-terminated string holding the answer,
+/// or an error message if the status isn't `AOC_STATUS_OK`. It is owned by the
+/// caller, and must be released with [`aoc_free_string`].
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes (it may be NULL if
+/// `input_len` is 0), and `answer` must be NULL or point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: *const u8,
+    input_len: usize,
+    answer: *mut *mut c_char,
+) -> AocStatus {
+    if answer.is_null() || (input.is_null() && input_len > 0) {
+        return AocStatus::NullPointer;
+    }
+    let input = if input_len == 0 {&[][..]} else {slice::from_raw_parts(input, input_len)};
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| solve(DayId::new(year, day), part, input)))
+        .unwrap_or_else(|_| Err((AocStatus::Panic, String::from("The solver panicked"))));
+    let (status, text) = match result {
+        Ok(text) => (AocStatus::Ok, text),
+        Err(err) => err,
+    };
+    *answer = into_c_string(text);
+    status
+}
+
+/// Releases a string returned by [`aoc_solve`]. Does nothing if `s` is NULL.
+///
+/// # Safety
+///
+/// `s` must be NULL or a string returned by this library that hasn't already
+/// been freed.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_free_string(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+fn solve(id: DayId, part: u8, input: &[u8]) -> Result<String, (AocStatus, String)> {
+    let day = aoc::find(id)
+        .ok_or_else(|| (AocStatus::NoSolution, format!("No solution for {id}")))?;
+    let input = std::str::from_utf8(input)
+        .map_err(|_| (AocStatus::InvalidInput, String::from("Input is not valid UTF-8")))?;
+    let (_, report) = day.run_part(input, usize::from(part), Budget::UNLIMITED)
+        .map_err(|err| {
+            let status = if err.is::<Panicked>() {AocStatus::Panic} else {AocStatus::InvalidInput};
+            (status, err.to_string())
+        })?;
+
+    match report {
+        Some(report) if report.failed => Err((AocStatus::NoAnswer, report.answer)),
+        Some(report) => Ok(report.answer),
+        None => Err((AocStatus::NoPart, format!("No solution for {id} part {part}"))),
+    }
+}
+
+fn into_c_string(s: String) -> *mut c_char {
+    // Answers and error messages shouldn't contain NUL, but don't trust that.
+    CString::new(s.replace('\0', "")).unwrap_or_default().into_raw()
+}
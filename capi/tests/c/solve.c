@@ -0,0 +1,52 @@
+/* Solves the day 1 example through the C ABI, and checks the error codes. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc.h"
+
+static const char EXAMPLE[] = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+static int failures = 0;
+
+static void check(uint16_t year, uint8_t day, uint8_t part, const char *input,
+                  AocStatus expected_status, const char *expected_answer) {
+    char *answer = NULL;
+    AocStatus status = aoc_solve(year, day, part, (const uint8_t *)input,
+                                 strlen(input), &answer);
+    if (status != expected_status
+            || (expected_answer != NULL && strcmp(answer, expected_answer) != 0)) {
+        fprintf(stderr, "%d/%02d part %d: got status %d (%s), expected %d (%s)\n",
+                year, day, part, status, answer, expected_status,
+                expected_answer != NULL ? expected_answer : "any message");
+        failures++;
+    }
+    aoc_free_string(answer);
+}
+
+int main(void) {
+    check(2024, 1, 1, EXAMPLE, AOC_STATUS_OK, "11");
+    check(2024, 1, 2, EXAMPLE, AOC_STATUS_OK, "31");
+    check(2024, 11, 1, "125 17", AOC_STATUS_OK, "55312");
+
+    check(1999, 1, 1, EXAMPLE, AOC_STATUS_NO_SOLUTION, "No solution for 1999/01");
+    check(2024, 1, 3, EXAMPLE, AOC_STATUS_NO_PART, "No solution for 2024/01 part 3");
+    check(2024, 16, 2, "#####\n#S.E#\n#####\n", AOC_STATUS_NO_PART, NULL);
+    check(2024, 1, 1, "3 four\n", AOC_STATUS_INVALID_INPUT, NULL);
+    check(2024, 1, 1, "\xff\n", AOC_STATUS_INVALID_INPUT, "Input is not valid UTF-8");
+    check(2024, 16, 1, "#####\n#S#E#\n#####\n", AOC_STATUS_NO_ANSWER, "No answer found");
+
+    /* The test fixture for 2000/01, enabled with AOC_FIXTURES, panics on a 0. */
+    check(2000, 1, 1, "1\n0\n", AOC_STATUS_PANIC, "Part 1 panicked: found a 0");
+
+    if (aoc_solve(2024, 1, 1, NULL, 1, NULL) != AOC_STATUS_NULL_POINTER) {
+        fprintf(stderr, "NULL pointers weren't rejected\n");
+        failures++;
+    }
+    aoc_free_string(NULL);
+
+    if (failures == 0) {
+        printf("ok\n");
+    }
+    return failures == 0 ? 0 : 1;
+}
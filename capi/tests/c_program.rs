@@ -0,0 +1,38 @@
+//! Compiles `c/solve.c` against the generated header, links it to the shared
+//! library, and runs it.
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+#[test]
+fn c_program() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // The test binary lives in `target/<profile>/deps`, where Cargo builds
+    // the shared library. The copy it puts next to `deps` is only updated by
+    // `cargo build`, so it can be out of date.
+    let exe = env::current_exe().unwrap();
+    let lib_dir = exe.parent().unwrap();
+    let program = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("solve");
+
+    let cc = env::var("CC").unwrap_or_else(|_| String::from("cc"));
+    let status = Command::new(cc)
+        .arg(manifest_dir.join("tests/c/solve.c"))
+        .arg("-I").arg(manifest_dir.join("include"))
+        .arg("-L").arg(lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .args(["-laoc_capi", "-Wall", "-Werror", "-o"])
+        .arg(&program)
+        .status()
+        .expect("failed to run the C compiler");
+    assert!(status.success(), "failed to compile the C program");
+
+    // Cargo's own library path for tests would find the stale copy first.
+    let output = Command::new(&program)
+        .env("LD_LIBRARY_PATH", lib_dir)
+        .env("AOC_FIXTURES", "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(output.stdout, b"ok\n");
+}
@@ -0,0 +1,97 @@
+//! Finds where a deterministic simulation starts repeating itself, so that it
+//! can be skipped ahead to any step without running every step in between.
+//!
+//! A simulation is a starting state and a step function, which returns `None`
+//! once the simulation has ended. Step `n` is the state after `n` steps.
+//!
+//! ```
+//! // 3, 9, 7, 1, 3, ... when multiplying by 3 modulo 20.
+//! let step = |&x: &u64| Some(x * 3 % 20);
+//! let cycle = cycle::brent(3, step).unwrap();
+//! assert_eq!(cycle, cycle::Cycle{start: 0, len: 4});
+//! assert_eq!(cycle.fast_forward(3, 1_000_000_001, step), Some(9));
+//! ```
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a simulation's states start repeating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The first step whose state comes up again.
+    pub start: usize,
+    /// The number of steps after which the states repeat.
+    pub len: usize,
+}
+
+impl Cycle {
+    /// The earliest step whose state is the same as step `n`'s.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.len
+        }
+    }
+
+    /// Step `n` of the simulation that has this cycle, running at most
+    /// `start + len` steps to get there.
+    pub fn fast_forward<S>(&self, initial: S, n: usize, mut step: impl FnMut(&S) -> Option<S>) -> Option<S> {
+        let mut state = initial;
+        for _ in 0..self.equivalent_step(n) {
+            state = step(&state)?;
+        }
+        Some(state)
+    }
+}
+
+/// Finds the cycle with Brent's algorithm, which only keeps two states at a
+/// time but runs the simulation up to about three times over. Returns `None`
+/// if the simulation ends instead.
+pub fn brent<S: Clone + PartialEq>(initial: S, mut step: impl FnMut(&S) -> Option<S>) -> Option<Cycle> {
+    // The hare runs ahead, and the tortoise waits for it at every power of
+    // two steps, until the hare comes round to it.
+    let mut power = 1;
+    let mut len = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial)?;
+    while tortoise != hare {
+        if power == len {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
+        }
+        hare = step(&hare)?;
+        len += 1;
+    }
+
+    // With the hare a whole cycle ahead, the two first meet where it starts.
+    let mut start = 0;
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..len {
+        hare = step(&hare)?;
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        start += 1;
+    }
+    Some(Cycle{start, len})
+}
+
+/// Finds the cycle by remembering every state, which runs the simulation just
+/// once, up to the first repeat. Returns `None` if the simulation ends instead.
+pub fn hashed<S: Hash + Eq>(initial: S, mut step: impl FnMut(&S) -> Option<S>) -> Option<Cycle> {
+    let mut seen = HashMap::new();
+    let mut state = initial;
+    for n in 0.. {
+        if let Some(&start) = seen.get(&state) {
+            return Some(Cycle{start, len: n - start});
+        }
+        let next = step(&state)?;
+        seen.insert(state, n);
+        state = next;
+    }
+    unreachable!("a simulation can't run for more than usize::MAX steps")
+}
@@ -0,0 +1,38 @@
+use cycle::Cycle;
+
+/// 0, 1, 2, 3, 4, 5, 6, 3, 4, 5, 6, ...
+fn rho(&x: &u32) -> Option<u32> {
+    Some(if x == 6 {3} else {x + 1})
+}
+
+#[test]
+fn both_algorithms_find_the_same_cycle() {
+    let expected = Cycle{start: 3, len: 4};
+    assert_eq!(cycle::brent(0, rho), Some(expected));
+    assert_eq!(cycle::hashed(0, rho), Some(expected));
+}
+
+#[test]
+fn a_fixed_point_is_a_cycle_of_one() {
+    let step = |&x: &u32| Some(x / 2);
+    assert_eq!(cycle::brent(100, step), Some(Cycle{start: 7, len: 1}));
+    assert_eq!(cycle::hashed(100, step), Some(Cycle{start: 7, len: 1}));
+}
+
+#[test]
+fn simulations_that_end_have_no_cycle() {
+    let step = |&x: &u32| x.checked_sub(1);
+    assert_eq!(cycle::brent(10, step), None);
+    assert_eq!(cycle::hashed(10, step), None);
+}
+
+#[test]
+fn fast_forwarding_matches_running_every_step() {
+    let cycle = cycle::brent(0, rho).unwrap();
+    let mut state = 0;
+    for n in 0..50 {
+        assert_eq!(cycle.fast_forward(0, n, rho), Some(state));
+        state = rho(&state).unwrap();
+    }
+    assert_eq!(cycle.equivalent_step(usize::MAX), 3 + (usize::MAX - 3) % 4);
+}
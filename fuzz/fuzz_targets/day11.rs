@@ -0,0 +1,6 @@
+#![no_main]
+
+use aoc2024::day11::Day11;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| aoc_fuzz::parse_and_solve::<Day11>(data));
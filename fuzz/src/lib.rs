@@ -0,0 +1,37 @@
+//! Fuzz targets for each day's parse-and-solve pipeline, run with
+//! [cargo-fuzz](https://github.com/rust-fuzz/cargo-fuzz) from this directory:
+//!
+//! ```text
+//! cargo +nightly fuzz run day17 -- -max_len=4096
+//! ```
+//!
+//! Each target's corpus is seeded with the puzzle's example input, in
+//! `corpus/<target>`. Any panic is a bug: malformed input should be rejected
+//! by `parse` with an error instead. So is a variant that gives a different
+//! answer than the default one.
+
+use runner::{Budget, Solution};
+
+/// Enough for every example, but small enough that searches that never end
+/// on some inputs don't look like hangs to the fuzzer.
+const BUDGET: Budget = Budget{time: None, steps: Some(100_000)};
+
+/// Parses `data` and solves both parts with every variant, ignoring inputs
+/// that are rejected.
+pub fn parse_and_solve<S: Solution>(data: &[u8]) {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(parsed) = S::parse(input) else {
+        return;
+    };
+    let part_1 = runner::cancel::with_budget(BUDGET, || Some(S::part_1(&parsed)));
+    let part_2 = runner::cancel::with_budget(BUDGET, || S::part_2(&parsed));
+    for variant in S::variants() {
+        let (answer, timed_out) = runner::cancel::with_budget(BUDGET, || (variant.solve)(&parsed));
+        let (expected, expected_timed_out) = if variant.part == 1 {&part_1} else {&part_2};
+        if !timed_out && !expected_timed_out {
+            assert_eq!(Some(&answer), expected.as_ref(), "{} part {}", variant.name, variant.part);
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Directions and neighbourhoods on a grid indexed by `(row, column)`.
+//!
+//! Coordinates are `usize` and wrap around on underflow, so a step off the top
+//! or left edge lands far out of bounds and is rejected by a plain bounds check.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {Up, Right, Down, Left}
+impl Direction {
+    /// Turns 90 degrees clockwise.
+    pub fn rotate(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up
+        }
+    }
+
+    pub fn offset_coords(self, i: usize, j: usize) -> (usize, usize) {
+        let i2 = match self {
+            Direction::Up   => i.wrapping_sub(1),
+            Direction::Down => i.wrapping_add(1),
+            _ => i
+        };
+        let j2 = match self {
+            Direction::Left  => j.wrapping_sub(1),
+            Direction::Right => j.wrapping_add(1),
+            _ => j
+        };
+        (i2, j2)
+    }
+}
+
+/// The four orthogonal neighbours of `(i, j)`: up, down, left, right.
+pub fn neighbors(i: usize, j: usize) -> [(usize, usize); 4] {
+    let up = (i.wrapping_sub(1), j);
+    let left = (i, j.wrapping_sub(1));
+    let down = (i.wrapping_add(1), j);
+    let right = (i, j.wrapping_add(1));
+    [up, down, left, right]
+}
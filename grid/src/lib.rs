@@ -0,0 +1,116 @@
+//! Helpers for puzzles whose input is a 2D grid, indexed by `(row, column)`.
+
+mod sparse;
+
+pub use sparse::{Bounds, Pos, SparseGrid};
+
+/// Splits the input into rows of bytes that borrow from the input.
+pub fn rows(input: &str) -> Vec<&[u8]> {
+    input.lines().map(str::as_bytes).collect()
+}
+
+/// Splits the input into rows of bytes that can be modified in place.
+pub fn rows_owned(input: &str) -> Vec<Vec<u8>> {
+    input.lines().map(Vec::from).collect()
+}
+
+/// Returns the cell at `(i, j)`, or `None` if it is out of bounds.
+pub fn get<T>(grid: &[impl AsRef<[T]>], (i, j): (usize, usize)) -> Option<&T> {
+    grid.get(i).and_then(|row| row.as_ref().get(j))
+}
+
+/// Finds the coordinates of the first cell equal to `val`, scanning row by row.
+pub fn find<T: PartialEq>(grid: &[impl AsRef<[T]>], val: &T) -> Option<(usize, usize)> {
+    grid.iter().enumerate()
+        .flat_map(|(i, row)| {
+            row.as_ref().iter().enumerate().map(move |(j, cell)| (i, j, cell))
+        })
+        .find(|(_, _, cell)| *cell == val)
+        .map(|(i, j, _)| (i, j))
+}
+
+/// A set of cells of a grid, stored as one bit per cell. Each cell can be in
+/// the set once per layer, e.g. once for each direction it was entered in.
+///
+/// Clearing it keeps its memory, so one set can be reused for many searches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellSet {
+    bits: Vec<u64>,
+    rows: usize,
+    cols: usize,
+    layers: usize,
+    len: usize,
+}
+
+impl CellSet {
+    /// A set of cells of a grid with `rows` rows of `cols` cells.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self::with_layers(rows, cols, 1)
+    }
+
+    /// A set that can hold each cell once for each of `layers` layers.
+    pub fn with_layers(rows: usize, cols: usize, layers: usize) -> Self {
+        let bit_count = rows * cols * layers;
+        Self{bits: vec![0; bit_count.div_ceil(64)], rows, cols, layers, len: 0}
+    }
+
+    /// A set of the cells of `grid`, which is as wide as its longest row.
+    pub fn covering<T>(grid: &[impl AsRef<[T]>]) -> Self {
+        Self::covering_with_layers(grid, 1)
+    }
+
+    /// A set of the cells of `grid`, with `layers` layers.
+    pub fn covering_with_layers<T>(grid: &[impl AsRef<[T]>], layers: usize) -> Self {
+        let cols = grid.iter().map(|row| row.as_ref().len()).max().unwrap_or(0);
+        Self::with_layers(grid.len(), cols, layers)
+    }
+
+    /// The bit for `(i, j)` in `layer`, or `None` if it is out of bounds.
+    fn bit(&self, layer: usize, (i, j): (usize, usize)) -> Option<(usize, u64)> {
+        if i >= self.rows || j >= self.cols || layer >= self.layers {
+            return None;
+        }
+        let index = (layer * self.rows + i) * self.cols + j;
+        Some((index / 64, 1 << (index % 64)))
+    }
+
+    /// Adds a cell. Returns whether it was newly added. Panics if the cell is
+    /// outside the grid, like indexing the grid would.
+    pub fn insert(&mut self, cell: (usize, usize)) -> bool {
+        self.insert_in(0, cell)
+    }
+
+    /// Whether the set has a cell. Cells outside the grid never are.
+    pub fn contains(&self, cell: (usize, usize)) -> bool {
+        self.contains_in(0, cell)
+    }
+
+    /// Adds a cell to one layer. Returns whether it was newly added.
+    pub fn insert_in(&mut self, layer: usize, cell: (usize, usize)) -> bool {
+        let Some((word, mask)) = self.bit(layer, cell) else {
+            panic!("cell {cell:?} in layer {layer} is outside a {}x{} grid with {} layers", self.rows, self.cols, self.layers);
+        };
+        let is_new = self.bits[word] & mask == 0;
+        self.bits[word] |= mask;
+        self.len += usize::from(is_new);
+        is_new
+    }
+
+    pub fn contains_in(&self, layer: usize, cell: (usize, usize)) -> bool {
+        self.bit(layer, cell).is_some_and(|(word, mask)| self.bits[word] & mask != 0)
+    }
+
+    /// The number of cells in the set, counting each layer separately.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.fill(0);
+        self.len = 0;
+    }
+}
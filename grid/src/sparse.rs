@@ -0,0 +1,178 @@
+use std::collections::{HashMap, HashSet};
+
+/// A cell of a [`SparseGrid`], as a signed `(row, column)`.
+pub type Pos = (i64, i64);
+
+/// The smallest rectangle holding some cells, with inclusive corners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Pos,
+    pub max: Pos,
+}
+
+impl Bounds {
+    pub fn contains(&self, (i, j): Pos) -> bool {
+        (self.min.0..=self.max.0).contains(&i) && (self.min.1..=self.max.1).contains(&j)
+    }
+
+    fn including(self, (i, j): Pos) -> Self {
+        Self{min: (self.min.0.min(i), self.min.1.min(j)), max: (self.max.0.max(i), self.max.1.max(j))}
+    }
+}
+
+/// A grid with values at only some of its cells, which can be anywhere,
+/// including at negative coordinates. It keeps track of the rectangle its
+/// cells are in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Pos, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self{cells: HashMap::new(), bounds: None}
+    }
+
+    /// The cells of a dense grid for which `keep` is true.
+    pub fn from_rows(grid: &[impl AsRef<[T]>], keep: impl Fn(&T) -> bool) -> Self where T: Clone {
+        let mut sparse = Self::new();
+        for (i, row) in grid.iter().enumerate() {
+            for (j, cell) in row.as_ref().iter().enumerate() {
+                if keep(cell) {
+                    sparse.insert((i as i64, j as i64), cell.clone());
+                }
+            }
+        }
+        sparse
+    }
+
+    /// Sets the value at `pos`, returning the one that was there.
+    pub fn insert(&mut self, pos: Pos, value: T) -> Option<T> {
+        self.bounds = Some(self.bounds.map_or(Bounds{min: pos, max: pos}, |bounds| bounds.including(pos)));
+        self.cells.insert(pos, value)
+    }
+
+    /// Clears `pos`, returning the value that was there.
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        let value = self.cells.remove(&pos)?;
+        let on_edge = self.bounds.is_some_and(|Bounds{min, max}| {
+            pos.0 == min.0 || pos.0 == max.0 || pos.1 == min.1 || pos.1 == max.1
+        });
+        if on_edge {
+            self.bounds = self.cells.keys().fold(None, |bounds, &pos| {
+                Some(bounds.map_or(Bounds{min: pos, max: pos}, |bounds: Bounds| bounds.including(pos)))
+            });
+        }
+        Some(value)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Clears every cell, keeping the memory for reuse.
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.bounds = None;
+    }
+
+    /// The rectangle the cells are in, or `None` if there are none.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// The cells with a value, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    /// The cells up, down, left and right of `pos` that have a value.
+    pub fn neighbors(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        neighbors(pos).filter_map(|pos| Some((pos, self.get(pos)?)))
+    }
+
+    /// Splits the cells into groups that are connected up, down, left and
+    /// right, in no particular order.
+    pub fn components(&self) -> Vec<Vec<Pos>> {
+        let mut seen = HashSet::with_capacity(self.len());
+        let mut components = vec![];
+        for &start in self.cells.keys() {
+            if !seen.insert(start) {
+                continue;
+            }
+            let mut component = vec![start];
+            let mut next = 0;
+            while let Some(&pos) = component.get(next) {
+                next += 1;
+                for (neighbor, _) in self.neighbors(pos) {
+                    if seen.insert(neighbor) {
+                        component.push(neighbor);
+                    }
+                }
+            }
+            components.push(component);
+        }
+        components
+    }
+
+    /// The number of groups of cells that are connected up, down, left and
+    /// right.
+    pub fn component_count(&self) -> usize {
+        self.components().len()
+    }
+
+    /// Draws the rectangle the cells are in, a line per row, with `cell` for
+    /// the cells with a value and `.` for the rest.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        let Some(Bounds{min, max}) = self.bounds else {
+            return String::new();
+        };
+        let mut out = String::new();
+        for i in min.0..=max.0 {
+            out.extend((min.1..=max.1).map(|j| self.get((i, j)).map_or('.', &cell)));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(Pos, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Pos, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (pos, value) in iter {
+            grid.insert(pos, value);
+        }
+        grid
+    }
+}
+
+/// The cells up, down, left and right of `pos`, leaving out any that would be
+/// past the limits of `i64`.
+fn neighbors((i, j): Pos) -> impl Iterator<Item = Pos> {
+    [(i.checked_sub(1), Some(j)), (i.checked_add(1), Some(j)), (Some(i), j.checked_sub(1)), (Some(i), j.checked_add(1))]
+        .into_iter()
+        .filter_map(|(i, j)| Some((i?, j?)))
+}
@@ -0,0 +1,46 @@
+use grid::CellSet;
+
+#[test]
+fn cells_are_added_once() {
+    let mut set = CellSet::new(3, 70);
+    assert!(set.insert((2, 69)));
+    assert!(!set.insert((2, 69)));
+    assert!(set.insert((0, 0)));
+    assert!(set.contains((2, 69)));
+    assert!(!set.contains((1, 69)));
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn layers_are_separate() {
+    let mut set = CellSet::with_layers(2, 2, 4);
+    assert!(set.insert_in(3, (1, 1)));
+    assert!(set.insert_in(0, (1, 1)));
+    assert!(!set.contains_in(1, (1, 1)));
+    assert!(set.contains((1, 1)));
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn cells_outside_the_grid_are_never_contained() {
+    let set = CellSet::covering(&["ab", "abcd"]);
+    assert!(!set.contains((0, 4)));
+    assert!(!set.contains((usize::MAX, 0)));
+    assert!(!set.contains_in(1, (0, 0)));
+}
+
+#[test]
+#[should_panic(expected = "outside a 2x4 grid")]
+fn inserting_outside_the_grid_panics() {
+    CellSet::covering(&["ab", "abcd"]).insert((2, 0));
+}
+
+#[test]
+fn clearing_empties_the_set() {
+    let mut set = CellSet::new(10, 10);
+    set.insert((5, 5));
+    set.clear();
+    assert!(set.is_empty());
+    assert!(!set.contains((5, 5)));
+    assert!(set.insert((5, 5)));
+}
@@ -0,0 +1,45 @@
+use grid::{Bounds, SparseGrid};
+
+#[test]
+fn bounds_grow_to_negative_coordinates() {
+    let mut grid = SparseGrid::new();
+    assert_eq!(grid.bounds(), None);
+    grid.insert((2, 3), 'a');
+    grid.insert((-1, 5), 'b');
+    assert_eq!(grid.bounds(), Some(Bounds{min: (-1, 3), max: (2, 5)}));
+    assert!(grid.bounds().unwrap().contains((0, 4)));
+}
+
+#[test]
+fn bounds_shrink_when_edge_cells_are_removed() {
+    let mut grid: SparseGrid<()> = [((0, 0), ()), ((1, 1), ()), ((-4, 7), ())].into_iter().collect();
+    assert_eq!(grid.remove((-4, 7)), Some(()));
+    assert_eq!(grid.bounds(), Some(Bounds{min: (0, 0), max: (1, 1)}));
+    grid.remove((0, 0));
+    grid.remove((1, 1));
+    assert_eq!(grid.bounds(), None);
+    assert!(grid.is_empty());
+}
+
+#[test]
+fn neighbors_are_the_occupied_adjacent_cells() {
+    let grid = SparseGrid::from_rows(&["#.#", "##.", "..#"], |&c| c == b'#');
+    let mut neighbors: Vec<_> = grid.neighbors((1, 0)).map(|(pos, _)| pos).collect();
+    neighbors.sort();
+    assert_eq!(neighbors, [(0, 0), (1, 1)]);
+    assert_eq!(grid.component_count(), 3);
+}
+
+#[test]
+fn neighbors_stop_at_the_limits_of_i64() {
+    let grid: SparseGrid<()> = [((i64::MIN, 0), ()), ((i64::MAX, 0), ())].into_iter().collect();
+    assert_eq!(grid.neighbors((i64::MIN, 0)).count(), 0);
+    assert_eq!(grid.component_count(), 2);
+}
+
+#[test]
+fn rendering_draws_the_occupied_region() {
+    let grid: SparseGrid<char> = [((-1, -1), 'a'), ((0, 1), 'b')].into_iter().collect();
+    assert_eq!(grid.render(|&c| c), "a..\n..b\n");
+    assert_eq!(SparseGrid::<char>::new().render(|&c| c), "");
+}
@@ -0,0 +1,114 @@
+//! A cache for recursive solvers that reach the same state many times, such
+//! as counting the ways to finish from each state.
+//!
+//! ```
+//! use memo::Memo;
+//!
+//! fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+//!     if n < 2 {
+//!         return n;
+//!     }
+//!     memo.get_or_insert_with(n, |memo| fibonacci(n-1, memo) + fibonacci(n-2, memo))
+//! }
+//!
+//! let mut memo = Memo::new();
+//! assert_eq!(fibonacci(90, &mut memo), 2880067194370816120);
+//! assert_eq!(memo.stats().misses, 89);
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::hash::Hash;
+
+/// Values computed for each state, keyed by the state. A bounded memo evicts
+/// its oldest entry to make room for a new one.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+    /// The keys in the order they were inserted, if the memo is bounded.
+    order: VecDeque<K>,
+    capacity: Option<usize>,
+    stats: Stats,
+}
+
+/// How often a [`Memo`] has been looked up, and how it went.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Lookups that found a value.
+    pub hits: u64,
+    /// Lookups that didn't.
+    pub misses: u64,
+    /// Entries removed to make room for new ones.
+    pub evictions: u64,
+    /// Entries in the memo now.
+    pub len: usize,
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} hits, {} misses, {} evictions, {} entries", self.hits, self.misses, self.evictions, self.len)
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self{values: HashMap::new(), order: VecDeque::new(), capacity: None, stats: Stats::default()}
+    }
+
+    /// A memo that holds at most `capacity` entries.
+    pub fn bounded(capacity: usize) -> Self {
+        Self{capacity: Some(capacity), ..Self::new()}
+    }
+
+    /// Looks up the value for `key`, counting a hit or a miss.
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        let value = self.values.get(key).cloned();
+        if value.is_some() {
+            self.stats.hits += 1;
+        } else {
+            self.stats.misses += 1;
+        }
+        value
+    }
+
+    /// Stores the value for `key`, evicting the oldest entry if the memo is
+    /// full.
+    pub fn insert(&mut self, key: K, value: V) {
+        let Some(capacity) = self.capacity else {
+            self.values.insert(key, value);
+            return;
+        };
+        if capacity == 0 {
+            return;
+        }
+        if self.values.insert(key.clone(), value).is_none() {
+            self.order.push_back(key);
+        }
+        while self.values.len() > capacity {
+            let Some(oldest) = self.order.pop_front() else {break;};
+            self.values.remove(&oldest);
+            self.stats.evictions += 1;
+        }
+    }
+
+    /// Looks up the value for `key`, or computes and stores it with `f` if it
+    /// is missing. `f` gets the memo back, so that it can recurse.
+    pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        let value = f(self);
+        self.insert(key, value.clone());
+        value
+    }
+
+    pub fn stats(&self) -> Stats {
+        Stats{len: self.values.len(), ..self.stats}
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
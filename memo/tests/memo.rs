@@ -0,0 +1,47 @@
+use memo::{Memo, Stats};
+
+#[test]
+fn hits_and_misses_are_counted() {
+    let mut memo = Memo::new();
+    assert_eq!(memo.get(&1), None);
+    memo.insert(1, "one");
+    assert_eq!(memo.get(&1), Some("one"));
+    assert_eq!(memo.get_or_insert_with(2, |_| "two"), "two");
+    assert_eq!(memo.get_or_insert_with(2, |_| unreachable!()), "two");
+    assert_eq!(memo.stats(), Stats{hits: 2, misses: 2, evictions: 0, len: 2});
+}
+
+#[test]
+fn bounded_memos_evict_the_oldest_entry() {
+    let mut memo = Memo::bounded(2);
+    memo.insert('a', 1);
+    memo.insert('b', 2);
+    memo.insert('a', 3);
+    memo.insert('c', 4);
+    assert_eq!(memo.get(&'a'), None);
+    assert_eq!(memo.get(&'b'), Some(2));
+    assert_eq!(memo.get(&'c'), Some(4));
+    assert_eq!(memo.stats(), Stats{hits: 2, misses: 1, evictions: 1, len: 2});
+}
+
+#[test]
+fn a_memo_with_no_room_remembers_nothing() {
+    let mut memo = Memo::bounded(0);
+    memo.insert(1, 1);
+    assert_eq!(memo.get(&1), None);
+    assert_eq!(memo.stats().len, 0);
+}
+
+#[test]
+fn eviction_never_changes_the_result() {
+    fn paths(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        if n < 2 {
+            return 1;
+        }
+        memo.get_or_insert_with(n, |memo| paths(n-1, memo) + paths(n-2, memo))
+    }
+    let expected = paths(60, &mut Memo::new());
+    let mut memo = Memo::bounded(3);
+    assert_eq!(paths(60, &mut memo), expected);
+    assert!(memo.stats().evictions > 0);
+}
@@ -0,0 +1,133 @@
+//! Helpers for parsing line-oriented puzzle input.
+
+use std::error::Error;
+use std::io;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+/// Parses every line of the input with `parse_line`, stopping at the first
+/// error.
+pub fn lines<T>(
+    input: impl io::BufRead,
+    mut parse_line: impl FnMut(&str) -> Result<T, Box<dyn Error>>,
+) -> Result<Vec<T>, Box<dyn Error>> {
+    input.lines()
+        .map(|line| parse_line(&line?))
+        .collect()
+}
+
+/// Calls `f` on every line of the input, stopping at the first error. Only
+/// one line is held in memory at a time, so this works on inputs of any size.
+pub fn for_each_line(
+    mut input: impl io::BufRead,
+    mut f: impl FnMut(&str) -> Result<(), Box<dyn Error>>,
+) -> Result<(), Box<dyn Error>> {
+    let mut line = String::new();
+    while input.read_line(&mut line)? != 0 {
+        f(line.strip_suffix('\n').map_or(&line, |line| line.strip_suffix('\r').unwrap_or(line)))?;
+        line.clear();
+    }
+    Ok(())
+}
+
+/// Reads the whole input into one buffer.
+pub fn read_all(mut input: impl io::Read) -> io::Result<Vec<u8>> {
+    let mut buffer = vec![];
+    input.read_to_end(&mut buffer)?;
+    Ok(buffer)
+}
+
+/// Splits the input into the same lines as [`io::BufRead::lines`] would,
+/// but borrows them instead of copying each into a `String`: a line ends at
+/// `\n` or `\r\n`, and there is no empty line after the last line break.
+pub fn byte_lines(input: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let mut rest = input;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let line = match rest.iter().position(|&b| b == b'\n') {
+            Some(end) => {
+                let line = &rest[..end];
+                rest = &rest[end+1..];
+                line.strip_suffix(b"\r").unwrap_or(line)
+            }
+            None => std::mem::take(&mut rest),
+        };
+        Some(line)
+    })
+}
+
+/// Parses every line of the input like [`lines`], but much faster on large
+/// inputs. Each line goes to `parse_bytes` first, which can assume that it is
+/// ASCII. Any line that it rejects is parsed again by `parse_line`, so that
+/// the results and errors are the same as from [`lines`] with `parse_line`,
+/// except that invalid UTF-8 is only noticed if `parse_bytes` looks at it.
+pub fn fast_lines<T>(
+    input: &[u8],
+    mut parse_bytes: impl FnMut(&[u8]) -> Result<T, Box<dyn Error>>,
+    mut parse_line: impl FnMut(&str) -> Result<T, Box<dyn Error>>,
+) -> Result<Vec<T>, Box<dyn Error>> {
+    byte_lines(input)
+        .map(|line| parse_bytes(line).or_else(|_| {
+            let line = std::str::from_utf8(line)
+                .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "stream did not contain valid UTF-8"))?;
+            parse_line(line)
+        }))
+        .collect()
+}
+
+/// Splits a line into the same words as [`str::split_whitespace`], as long as
+/// it is ASCII.
+pub fn words(line: &[u8]) -> impl Iterator<Item = &[u8]> {
+    // The ASCII characters that `char::is_whitespace` accepts, which include
+    // the vertical tab, unlike `u8::is_ascii_whitespace`.
+    line.split(|b| matches!(b, b' ' | b'\t' | b'\n' | b'\x0B' | b'\x0C' | b'\r'))
+        .filter(|word| !word.is_empty())
+}
+
+/// Integer types that [`int`] can parse.
+pub trait Integer: FromStr<Err = ParseIntError> {
+    /// Parses an optional sign and decimal digits, or returns `None` if
+    /// `digits` is anything else or doesn't fit.
+    fn from_ascii(digits: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty)*) => {$(
+        impl Integer for $t {
+            fn from_ascii(digits: &[u8]) -> Option<Self> {
+                let (is_negative, digits) = match digits {
+                    [b'-', rest @ ..] if <$t>::MIN != 0 => (true, rest),
+                    [b'+', rest @ ..] => (false, rest),
+                    _ => (false, digits),
+                };
+                if digits.is_empty() {
+                    return None;
+                }
+                let mut n: $t = 0;
+                for &digit in digits {
+                    let digit = digit.wrapping_sub(b'0');
+                    if digit > 9 {
+                        return None;
+                    }
+                    n = n.checked_mul(10)?;
+                    n = if is_negative {n.checked_sub(digit as $t)?} else {n.checked_add(digit as $t)?};
+                }
+                Some(n)
+            }
+        }
+    )*};
+}
+
+impl_integer!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+/// Parses an integer the way [`str::parse`] does, without checking that it is
+/// UTF-8 first.
+pub fn int<T: Integer>(word: &[u8]) -> Result<T, ParseIntError> {
+    match T::from_ascii(word) {
+        Some(n) => Ok(n),
+        // Parse it again to get the same error.
+        None => String::from_utf8_lossy(word).parse(),
+    }
+}
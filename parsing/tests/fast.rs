@@ -0,0 +1,74 @@
+use std::error::Error;
+use std::io::BufRead;
+use std::num::ParseIntError;
+
+/// Inputs with every kind of line break and whitespace, and numbers that are
+/// just valid, just invalid, or not ASCII.
+const INPUTS: [&str; 10] = [
+    "",
+    "\n",
+    "1 2\n3 4",
+    "1 2\r\n3 4\r\n",
+    "1 2\r",
+    "1\t2\x0B3\x0C4\r5\n\n\n6",
+    "+1 -2 --3 +-4 - + 0 -0 +0 007",
+    "127 128 -128 -129 255 256 65535 4294967295 18446744073709551615 18446744073709551616",
+    "1\u{a0}2 3\u{2003}4 ５ x1 1x",
+    "1 é\r\n\r\n2",
+];
+
+#[test]
+fn lines_are_split_like_buf_read() {
+    for input in INPUTS {
+        let expected: Vec<String> = input.as_bytes().lines().collect::<Result<_,_>>().unwrap();
+        let lines: Vec<&[u8]> = parsing::byte_lines(input.as_bytes()).collect();
+        assert_eq!(lines, expected.iter().map(String::as_bytes).collect::<Vec<_>>(), "{input:?}");
+    }
+}
+
+#[test]
+fn ascii_words_are_split_like_split_whitespace() {
+    for input in INPUTS.iter().filter(|input| input.is_ascii()) {
+        let words: Vec<&[u8]> = parsing::words(input.as_bytes()).collect();
+        let expected: Vec<&[u8]> = input.split_whitespace().map(str::as_bytes).collect();
+        assert_eq!(words, expected, "{input:?}");
+    }
+}
+
+fn assert_same_ints<T>(words: &[&str])
+where
+    T: parsing::Integer + std::str::FromStr<Err = ParseIntError> + PartialEq + std::fmt::Debug,
+{
+    for word in words {
+        assert_eq!(parsing::int::<T>(word.as_bytes()), word.parse::<T>(), "{word:?} as {}", std::any::type_name::<T>());
+    }
+}
+
+#[test]
+fn integers_are_parsed_like_str_parse() {
+    let words: Vec<&str> = INPUTS.iter().flat_map(|input| input.split_whitespace()).collect();
+    assert_same_ints::<i8>(&words);
+    assert_same_ints::<u8>(&words);
+    assert_same_ints::<i32>(&words);
+    assert_same_ints::<u32>(&words);
+    assert_same_ints::<i64>(&words);
+    assert_same_ints::<u64>(&words);
+    assert_same_ints::<usize>(&words);
+    assert_same_ints::<u128>(&words);
+}
+
+#[test]
+fn fast_lines_matches_lines() {
+    fn parse_line(line: &str) -> Result<Vec<i32>, Box<dyn Error>> {
+        Ok(line.split_whitespace().map(str::parse).collect::<Result<_,_>>()?)
+    }
+    fn parse_bytes(line: &[u8]) -> Result<Vec<i32>, Box<dyn Error>> {
+        Ok(parsing::words(line).map(parsing::int).collect::<Result<_,_>>()?)
+    }
+
+    for input in INPUTS.iter().chain(&["1 2\n3\u{a0}4\n", "1\n2 x\n"]) {
+        let expected = parsing::lines(input.as_bytes(), parse_line).map_err(|err| err.to_string());
+        let parsed = parsing::fast_lines(input.as_bytes(), parse_bytes, parse_line).map_err(|err| err.to_string());
+        assert_eq!(parsed, expected, "{input:?}");
+    }
+}
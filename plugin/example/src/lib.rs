@@ -0,0 +1,54 @@
+//! An example solver plugin for 2024 day 1, built as
+//! `libaoc_plugin_example.so`. Copy it into the plugins directory to have the
+//! runner check it against its own solver.
+
+use std::collections::HashMap;
+use std::error::Error;
+
+aoc_plugin::declare_plugin!(2024, 1, [
+    (1, "counts", part_1_counts),
+    (2, "counts", part_2_counts),
+]);
+
+fn parse(input: &str) -> Result<(Vec<u32>, Vec<u32>), Box<dyn Error>> {
+    let mut left = vec![];
+    let mut right = vec![];
+    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+        let mut numbers = line.split_whitespace().map(str::parse::<u32>);
+        let (Some(a), Some(b), None) = (numbers.next(), numbers.next(), numbers.next()) else {
+            return Err(format!("Expected two numbers on a line, found {line:?}").into());
+        };
+        left.push(a?);
+        right.push(b?);
+    }
+    Ok((left, right))
+}
+
+/// Sorts only the distinct numbers of each list, with how often each comes
+/// up, instead of the whole lists.
+fn part_1_counts(input: &str) -> Result<String, Box<dyn Error>> {
+    fn sorted(list: &[u32]) -> impl Iterator<Item = u32> {
+        let mut counts: Vec<_> = counts(list).into_iter().collect();
+        counts.sort_unstable();
+        counts.into_iter().flat_map(|(n, count)| std::iter::repeat_n(n, count as usize))
+    }
+
+    let (left, right) = parse(input)?;
+    let distance: u64 = sorted(&left).zip(sorted(&right)).map(|(a, b)| u64::from(a.abs_diff(b))).sum();
+    Ok(distance.to_string())
+}
+
+fn part_2_counts(input: &str) -> Result<String, Box<dyn Error>> {
+    let (left, right) = parse(input)?;
+    let right = counts(&right);
+    let similarity: u64 = left.iter().map(|n| u64::from(*n) * right.get(n).copied().unwrap_or(0)).sum();
+    Ok(similarity.to_string())
+}
+
+fn counts(list: &[u32]) -> HashMap<u32, u64> {
+    let mut counts = HashMap::new();
+    for &n in list {
+        *counts.entry(n).or_default() += 1;
+    }
+    counts
+}
@@ -0,0 +1,57 @@
+//! Loads the example plugin from the shared library that Cargo builds next to
+//! the test binary, and checks it against the built-in solver.
+
+use std::env::{self, consts};
+use std::fs;
+use std::path::PathBuf;
+
+use aoc::plugins::{self, Plugin};
+use runner::DayId;
+
+const INPUT: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+fn library_path() -> PathBuf {
+    // The test binary lives in `target/<profile>/deps`, next to the freshly
+    // built library.
+    let exe = env::current_exe().unwrap();
+    let lib_dir = exe.parent().unwrap();
+    lib_dir.join(format!("{}aoc_plugin_example{}", consts::DLL_PREFIX, consts::DLL_SUFFIX))
+}
+
+#[test]
+fn plugin_agrees_with_the_built_in_solver() {
+    let plugin = unsafe {Plugin::load(&library_path())}.unwrap();
+    assert_eq!(plugin.id, DayId::new(2024, 1));
+    assert_eq!(plugin.variants, [(1, String::from("counts")), (2, String::from("counts"))]);
+
+    let report = aoc::find(plugin.id).unwrap().run(INPUT).unwrap();
+    let checks = plugin.check(INPUT, &report);
+    let answers: Vec<_> = checks.iter().map(|check| check.answer.clone()).collect();
+    assert_eq!(answers, [Ok(String::from("11")), Ok(String::from("31"))]);
+    assert!(checks.iter().all(|check| check.agrees()));
+}
+
+#[test]
+fn plugin_errors_are_returned() {
+    let plugin = unsafe {Plugin::load(&library_path())}.unwrap();
+    assert_eq!(plugin.solve(0, "1 2 3\n"), Err(String::from("Expected two numbers on a line, found \"1 2 3\"")));
+    assert_eq!(plugin.solve(2, INPUT), Err(String::from("No such variant")));
+}
+
+#[test]
+fn only_shared_libraries_in_the_directory_are_loaded() {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("plugins");
+    fs::create_dir_all(&dir).unwrap();
+    fs::copy(library_path(), dir.join(format!("example.{}", consts::DLL_EXTENSION))).unwrap();
+    fs::write(dir.join(format!("broken.{}", consts::DLL_EXTENSION)), "not a library").unwrap();
+    fs::write(dir.join("README"), "not a plugin").unwrap();
+
+    let loaded = unsafe {plugins::load_dir(&dir)}.unwrap();
+    let [Err(broken), Ok(example)] = &loaded[..] else {
+        panic!("expected the broken library to fail to load, and the example to load");
+    };
+    assert_eq!(broken.path, dir.join(format!("broken.{}", consts::DLL_EXTENSION)));
+    assert_eq!(example.id, DayId::new(2024, 1));
+
+    assert!(unsafe {plugins::load_dir(&dir.join("missing"))}.unwrap().is_empty());
+}
@@ -0,0 +1,140 @@
+//! The ABI between the runner and solver plugins: shared libraries, built
+//! outside this workspace, that solve some parts of one day in their own way.
+//! The runner loads every plugin in its plugins directory, see
+//! `aoc::plugins`, and checks the plugin's answers against its own.
+//!
+//! A plugin is a `cdylib` crate that depends on this one and declares itself
+//! with [`declare_plugin!`]:
+//!
+//! ```
+//! # fn main() {}
+//! fn part_1_faster(input: &str) -> Result<String, Box<dyn std::error::Error>> {
+//!     Ok(input.lines().count().to_string())
+//! }
+//!
+//! aoc_plugin::declare_plugin!(2024, 1, [
+//!     (1, "faster", part_1_faster),
+//! ]);
+//! ```
+
+use std::error::Error;
+use std::ffi::{c_char, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+
+/// The version of [`Declaration`] that this crate declares plugins with. The
+/// runner refuses plugins with any other version.
+pub const ABI_VERSION: u32 = 1;
+
+/// The symbol of the `extern "C" fn() -> *const Declaration` that every
+/// plugin exports.
+pub const DECLARATION_SYMBOL: &[u8] = b"aoc_plugin_declaration\0";
+
+/// Solves a part of a day with a plugin's `variant`, which indexes its
+/// variants. On return, `*answer` holds the answer, or an error message if
+/// this returns false, and must be released with the plugin's `free_string`.
+pub type SolveFn = unsafe extern "C" fn(variant: usize, input: *const u8, input_len: usize, answer: *mut *mut c_char) -> bool;
+
+/// What a plugin solves, and how to call it. Every later version of the ABI
+/// keeps `abi_version` as the first field, so that it can always be checked.
+#[repr(C)]
+pub struct Declaration {
+    pub abi_version: u32,
+    pub year: u16,
+    pub day: u8,
+    pub variants: *const Variant,
+    pub variant_count: usize,
+    pub solve: SolveFn,
+    pub free_string: unsafe extern "C" fn(s: *mut c_char),
+}
+
+/// A way of solving one part of the plugin's day.
+#[repr(C)]
+pub struct Variant {
+    /// A NUL-terminated name, unique among the plugin's variants for the
+    /// same part.
+    pub name: *const c_char,
+    /// The part it solves, numbered from 1.
+    pub part: u8,
+}
+
+// Declarations only point to static data that is never modified.
+unsafe impl Sync for Declaration {}
+unsafe impl Sync for Variant {}
+
+/// A plugin's solver for one part, given the whole puzzle input.
+pub type Solver = fn(&str) -> Result<String, Box<dyn Error>>;
+
+/// Calls `solver` the way a [`SolveFn`] is called, turning invalid UTF-8 and
+/// panics into errors, as they must not unwind into the runner.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes (it may be NULL if
+/// `input_len` is 0), and `answer` must point to writable memory.
+pub unsafe fn solve_with(solver: Solver, input: *const u8, input_len: usize, answer: *mut *mut c_char) -> bool {
+    let input = if input_len == 0 {&[][..]} else {slice::from_raw_parts(input, input_len)};
+    let result = match std::str::from_utf8(input) {
+        Ok(input) => panic::catch_unwind(AssertUnwindSafe(|| solver(input).map_err(|err| err.to_string())))
+            .unwrap_or_else(|_| Err(String::from("The solver panicked"))),
+        Err(_) => Err(String::from("Input is not valid UTF-8")),
+    };
+    let (ok, text) = match result {
+        Ok(text) => (true, text),
+        Err(text) => (false, text),
+    };
+    *answer = CString::new(text.replace('\0', "")).unwrap_or_default().into_raw();
+    ok
+}
+
+/// Releases a string handed out by [`solve_with`]. Does nothing if `s` is
+/// NULL.
+///
+/// # Safety
+///
+/// `s` must be NULL or a string from [`solve_with`] in the same library that
+/// hasn't already been freed.
+pub unsafe extern "C" fn free_string(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Exports the [`Declaration`] of a plugin for `year` and `day`, with a
+/// `(part, "name", solver)` entry per variant, where each solver is a
+/// [`Solver`].
+#[macro_export]
+macro_rules! declare_plugin {
+    ($year:expr, $day:expr, [$(($part:expr, $name:literal, $solver:path)),* $(,)?]) => {
+        #[doc(hidden)]
+        pub mod __aoc_plugin {
+            use super::*;
+
+            static VARIANTS: &[$crate::Variant] = &[
+                $($crate::Variant{name: concat!($name, "\0").as_ptr().cast(), part: $part}),*
+            ];
+            static SOLVERS: &[$crate::Solver] = &[$($solver),*];
+            static DECLARATION: $crate::Declaration = $crate::Declaration{
+                abi_version: $crate::ABI_VERSION,
+                year: $year,
+                day: $day,
+                variants: VARIANTS.as_ptr(),
+                variant_count: VARIANTS.len(),
+                solve,
+                free_string: $crate::free_string,
+            };
+
+            unsafe extern "C" fn solve(variant: usize, input: *const u8, input_len: usize, answer: *mut *mut ::std::ffi::c_char) -> bool {
+                match SOLVERS.get(variant) {
+                    Some(&solver) => $crate::solve_with(solver, input, input_len, answer),
+                    None => $crate::solve_with(|_| Err("No such variant".into()), input, input_len, answer),
+                }
+            }
+
+            #[no_mangle]
+            pub extern "C" fn aoc_plugin_declaration() -> *const $crate::Declaration {
+                &DECLARATION
+            }
+        }
+    };
+}
@@ -0,0 +1,103 @@
+//! Python bindings for the solvers, built as the `aoc_solvers` extension
+//! module with `maturin develop` (or `maturin build`) in this directory.
+//!
+//! Each day is a submodule, e.g. `aoc_solvers.y2024.day01`, with `parse`,
+//! `part_1` and `part_2` functions. Parsed inputs are plain Python values
+//! (lists, tuples, dicts, ...), so they can be modified before solving.
+
+use std::error::Error;
+
+use pyo3::exceptions::{PyKeyError, PyTimeoutError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+mod y2024;
+
+#[pymodule]
+pub mod aoc_solvers {
+    use pyo3::prelude::*;
+    use runner::DayId;
+
+    #[pymodule_export]
+    use crate::y2024::y2024;
+
+    #[pymodule_init]
+    fn init(m: &Bound<'_, PyModule>) -> PyResult<()> {
+        super::register_submodules(m)
+    }
+
+    /// Solves a day's puzzle, returning the answer to each part. Raises a
+    /// `ValueError` if a part fails on the input, and a `TimeoutError` if it
+    /// runs out of time.
+    #[pyfunction]
+    fn solve(year: u16, day: u8, input: &str) -> PyResult<Vec<String>> {
+        let id = DayId::new(year, day);
+        let day = aoc::find(id)
+            .ok_or_else(|| super::PyValueError::new_err(format!("No solution for {id}")))?;
+        let report = day.run(input).map_err(super::value_error)?;
+        report.parts.into_iter().enumerate().map(|(i, part)| {
+            if part.timed_out {
+                Err(super::timeout_error(runner::TimedOut))
+            } else if part.failed {
+                Err(super::PyValueError::new_err(format!("Part {}: {}", i + 1, part.answer)))
+            } else {
+                Ok(part.answer)
+            }
+        }).collect()
+    }
+}
+
+/// Adds every submodule to `sys.modules`, so that Python can import them by
+/// their dotted names, e.g. `from aoc_solvers.y2024 import day01`.
+fn register_submodules(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    let sys_modules = module.py().import("sys")?.getattr("modules")?;
+    let prefix = module.name()?;
+    for item in module.dict().values() {
+        let Ok(submodule) = item.downcast_into::<PyModule>() else {continue;};
+        let name = format!("{prefix}.{}", submodule.name()?);
+        submodule.setattr("__name__", &name)?;
+        sys_modules.set_item(&name, &submodule)?;
+        register_submodules(&submodule)?;
+    }
+    Ok(())
+}
+
+fn value_error(err: Box<dyn Error>) -> PyErr {
+    PyValueError::new_err(err.to_string())
+}
+
+fn timeout_error(err: runner::TimedOut) -> PyErr {
+    PyTimeoutError::new_err(err.to_string())
+}
+
+/// Raises a `TimeoutError` if the solver ran out of time, and a `ValueError`
+/// for anything else that stopped it.
+fn solver_error(err: Box<dyn Error>) -> PyErr {
+    match err.downcast::<runner::TimedOut>() {
+        Ok(err) => timeout_error(*err),
+        Err(err) => value_error(err),
+    }
+}
+
+/// Extracts `dict[key]`, raising a `KeyError` if it is missing.
+fn get_item<'py, T: FromPyObject<'py>>(dict: &Bound<'py, PyDict>, key: &str) -> PyResult<T> {
+    dict.get_item(key)?
+        .ok_or_else(|| PyKeyError::new_err(key.to_string()))?
+        .extract()
+}
+
+/// Borrows a list of row strings as the `&[&[u8]]` grids some days take.
+fn rows(grid: &[String]) -> Vec<&[u8]> {
+    grid.iter().map(|row| row.as_bytes()).collect()
+}
+
+/// Grids are passed to Python as a list of strings, one per row.
+fn grid_to_py(grid: &[Vec<u8>]) -> Vec<String> {
+    grid.iter()
+        .map(|row| String::from_utf8_lossy(row).into_owned())
+        .collect()
+}
+
+fn grid_from_py(rows: Vec<String>) -> Vec<Vec<u8>> {
+    rows.into_iter().map(String::into_bytes).collect()
+}
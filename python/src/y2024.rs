@@ -0,0 +1,580 @@
+use pyo3::prelude::*;
+
+#[pymodule]
+pub mod y2024 {
+    use super::*;
+
+    #[pymodule]
+    mod day01 {
+        use pyo3::prelude::*;
+        use aoc2024::day01;
+
+        #[pyfunction]
+        fn parse(input: &str) -> PyResult<Vec<(i32, i32)>> {
+            day01::parse_input(input.as_bytes()).map_err(crate::value_error)
+        }
+
+        #[pyfunction]
+        fn part_1(input: Vec<(i32, i32)>) -> i64 {
+            day01::solve_part_1(&input)
+        }
+
+        #[pyfunction]
+        fn part_2(input: Vec<(i32, i32)>) -> i64 {
+            day01::solve_part_2(&input)
+        }
+    }
+
+    #[pymodule]
+    mod day02 {
+        use pyo3::prelude::*;
+        use aoc2024::day02;
+
+        #[pyfunction]
+        fn parse(input: &str) -> PyResult<Vec<Vec<i32>>> {
+            day02::parse_input(input.as_bytes()).map_err(crate::value_error)
+        }
+
+        #[pyfunction]
+        fn part_1(reports: Vec<Vec<i32>>) -> usize {
+            day02::solve_part_1(&reports)
+        }
+
+        #[pyfunction]
+        fn part_2(reports: Vec<Vec<i32>>) -> usize {
+            day02::solve_part_2(&reports)
+        }
+    }
+
+    #[pymodule]
+    mod day03 {
+        use pyo3::prelude::*;
+        use aoc2024::day03;
+
+        /// The corrupted memory is solved as-is, so parsing is a no-op.
+        #[pyfunction]
+        fn parse(input: &str) -> &str {
+            input
+        }
+
+        #[pyfunction]
+        fn part_1(memory: &str) -> u64 {
+            day03::solve_part_1(memory)
+        }
+
+        #[pyfunction]
+        fn part_2(memory: &str) -> u64 {
+            day03::solve_part_2(memory)
+        }
+    }
+
+    #[pymodule]
+    mod day04 {
+        use pyo3::prelude::*;
+        use aoc2024::day04;
+
+        #[pyfunction]
+        fn parse(input: &str) -> Vec<String> {
+            input.lines().map(String::from).collect()
+        }
+
+        #[pyfunction]
+        fn part_1(grid: Vec<String>) -> usize {
+            day04::solve_part_1(&crate::rows(&grid))
+        }
+
+        #[pyfunction]
+        fn part_2(grid: Vec<String>) -> usize {
+            day04::solve_part_2(&crate::rows(&grid))
+        }
+    }
+
+    #[pymodule]
+    mod day05 {
+        use pyo3::prelude::*;
+        use aoc2024::day05::{self, PuzzleInput};
+
+        /// Returns `(rules, updates)`, where `rules` maps each page to the set
+        /// of pages that must come after it.
+        #[pyfunction]
+        fn parse(input: &str) -> PyResult<PuzzleInput> {
+            day05::parse_input(input.as_bytes()).map_err(crate::value_error)
+        }
+
+        #[pyfunction]
+        fn part_1(input: PuzzleInput) -> i32 {
+            day05::solve_part_1(&input)
+        }
+
+        #[pyfunction]
+        fn part_2(input: PuzzleInput) -> i32 {
+            day05::solve_part_2(&input)
+        }
+    }
+
+    #[pymodule]
+    mod day06 {
+        use pyo3::prelude::*;
+        use aoc2024::day06;
+
+        #[pyfunction]
+        fn parse(input: &str) -> Vec<String> {
+            input.lines().map(String::from).collect()
+        }
+
+        #[pyfunction]
+        fn part_1(grid: Vec<String>) -> PyResult<usize> {
+            day06::solve_part_1(&crate::rows(&grid)).map_err(crate::timeout_error)
+        }
+
+        #[pyfunction]
+        fn part_2(grid: Vec<String>) -> PyResult<usize> {
+            day06::solve_part_2(&crate::rows(&grid)).map_err(crate::timeout_error)
+        }
+    }
+
+    #[pymodule]
+    mod day07 {
+        use pyo3::prelude::*;
+        use aoc2024::day07::{self, Equation};
+
+        /// Returns a list of `(test_value, operands)` pairs.
+        #[pyfunction]
+        fn parse(input: &str) -> PyResult<Vec<Equation>> {
+            day07::parse_input(input.as_bytes()).map_err(crate::value_error)
+        }
+
+        #[pyfunction]
+        fn part_1(equations: Vec<Equation>) -> u128 {
+            day07::solve_part_1(&equations)
+        }
+
+        #[pyfunction]
+        fn part_2(equations: Vec<Equation>) -> u128 {
+            day07::solve_part_2(&equations)
+        }
+    }
+
+    #[pymodule]
+    mod day08 {
+        use pyo3::prelude::*;
+        use aoc2024::day08;
+
+        #[pyfunction]
+        fn parse(input: &str) -> Vec<String> {
+            input.lines().map(String::from).collect()
+        }
+
+        #[pyfunction]
+        fn part_1(map: Vec<String>) -> usize {
+            day08::solve_part_1(&crate::rows(&map))
+        }
+
+        #[pyfunction]
+        fn part_2(map: Vec<String>) -> usize {
+            day08::solve_part_2(&crate::rows(&map))
+        }
+    }
+
+    #[pymodule]
+    mod day09 {
+        use pyo3::prelude::*;
+        use aoc2024::day09;
+
+        #[pyfunction]
+        fn parse(input: &str) -> PyResult<Vec<u32>> {
+            day09::parse_input(input.as_bytes()).map_err(crate::value_error)
+        }
+
+        #[pyfunction]
+        fn part_1(disk_map: Vec<u32>) -> usize {
+            day09::solve_part_1(&disk_map)
+        }
+
+        #[pyfunction]
+        fn part_2(disk_map: Vec<u32>) -> usize {
+            day09::solve_part_2(&disk_map)
+        }
+    }
+
+    #[pymodule]
+    mod day10 {
+        use pyo3::prelude::*;
+        use aoc2024::day10;
+
+        #[pyfunction]
+        fn parse(input: &str) -> PyResult<Vec<Vec<u32>>> {
+            day10::parse_input(input.as_bytes()).map_err(crate::value_error)
+        }
+
+        #[pyfunction]
+        fn part_1(map: Vec<Vec<u32>>) -> usize {
+            day10::solve_part_1(&map)
+        }
+
+        #[pyfunction]
+        fn part_2(map: Vec<Vec<u32>>) -> usize {
+            day10::solve_part_2(&map)
+        }
+    }
+
+    #[pymodule]
+    mod day11 {
+        use memo::Memo;
+        use pyo3::prelude::*;
+        use aoc2024::day11::{self, Stone};
+
+        #[pyfunction]
+        fn parse(input: &str) -> PyResult<Vec<Stone>> {
+            day11::parse_input(input.as_bytes()).map_err(crate::value_error)
+        }
+
+        #[pyfunction]
+        fn part_1(stones: Vec<Stone>) -> PyResult<u128> {
+            day11::solve_part_1(&stones).map_err(|err| crate::value_error(err.into()))
+        }
+
+        #[pyfunction]
+        fn part_2(stones: Vec<Stone>) -> PyResult<u128> {
+            day11::solve_part_2(&stones).map_err(|err| crate::value_error(err.into()))
+        }
+
+        /// How many stones a single stone turns into after blinking.
+        #[pyfunction]
+        fn count_resulting_stones(stone: Stone, blinks: usize) -> PyResult<u128> {
+            day11::count_resulting_stones(stone, blinks, &mut Memo::new())
+                .map_err(|err| crate::value_error(err.into()))
+        }
+    }
+
+    #[pymodule]
+    mod day12 {
+        use pyo3::prelude::*;
+        use pyo3::types::PyDict;
+        use aoc2024::day12::{self, PuzzleInput};
+
+        /// Returns `{"grid": ..., "region_count": ...}`, where each cell of
+        /// the grid holds the number of the region it belongs to.
+        #[pyfunction]
+        fn parse<'py>(py: Python<'py>, input: &str) -> PyResult<Bound<'py, PyDict>> {
+            let input = day12::parse_input(input.as_bytes()).map_err(crate::value_error)?;
+            let dict = PyDict::new(py);
+            dict.set_item("grid", input.grid)?;
+            dict.set_item("region_count", input.region_count)?;
+            Ok(dict)
+        }
+
+        fn from_py(input: &Bound<'_, PyDict>) -> PyResult<PuzzleInput> {
+            Ok(PuzzleInput{
+                grid: crate::get_item(input, "grid")?,
+                region_count: crate::get_item(input, "region_count")?,
+            })
+        }
+
+        #[pyfunction]
+        fn part_1(input: &Bound<'_, PyDict>) -> PyResult<usize> {
+            Ok(day12::solve_part_1(&from_py(input)?))
+        }
+
+        #[pyfunction]
+        fn part_2(input: &Bound<'_, PyDict>) -> PyResult<usize> {
+            Ok(day12::solve_part_2(&from_py(input)?))
+        }
+    }
+
+    #[pymodule]
+    mod day13 {
+        use pyo3::prelude::*;
+        use pyo3::types::PyDict;
+        use aoc2024::day13::{self, ClawMachine};
+
+        fn to_py<'py>(py: Python<'py>, machine: &ClawMachine) -> PyResult<Bound<'py, PyDict>> {
+            let dict = PyDict::new(py);
+            dict.set_item("button_a", machine.button_a)?;
+            dict.set_item("button_b", machine.button_b)?;
+            dict.set_item("prize", machine.prize)?;
+            Ok(dict)
+        }
+
+        fn from_py(machine: &Bound<'_, PyDict>) -> PyResult<ClawMachine> {
+            Ok(ClawMachine{
+                button_a: crate::get_item(machine, "button_a")?,
+                button_b: crate::get_item(machine, "button_b")?,
+                prize: crate::get_item(machine, "prize")?,
+            })
+        }
+
+        /// Returns a list of `{"button_a": (x, y), "button_b": (x, y),
+        /// "prize": (x, y)}` machines.
+        #[pyfunction]
+        fn parse<'py>(py: Python<'py>, input: &str) -> PyResult<Vec<Bound<'py, PyDict>>> {
+            let machines = day13::parse_input(input.as_bytes()).map_err(crate::value_error)?;
+            machines.iter().map(|machine| to_py(py, machine)).collect()
+        }
+
+        #[pyfunction]
+        fn part_1(machines: Vec<Bound<'_, PyDict>>) -> PyResult<i128> {
+            let machines = machines.iter().map(from_py).collect::<PyResult<Vec<_>>>()?;
+            Ok(day13::solve_part_1(&machines))
+        }
+
+        #[pyfunction]
+        fn part_2(machines: Vec<Bound<'_, PyDict>>) -> PyResult<i128> {
+            let machines = machines.iter().map(from_py).collect::<PyResult<Vec<_>>>()?;
+            Ok(day13::solve_part_2(&machines))
+        }
+
+        /// The tokens needed to win the prize, or `None` if it can't be won.
+        #[pyfunction]
+        fn tokens_to_win(machine: &Bound<'_, PyDict>) -> PyResult<Option<i128>> {
+            Ok(day13::tokens_to_win(&from_py(machine)?))
+        }
+    }
+
+    #[pymodule]
+    mod day14 {
+        use pyo3::prelude::*;
+        use pyo3::types::PyDict;
+        use aoc2024::day14::{self, Robot};
+
+        fn to_py<'py>(py: Python<'py>, robot: &Robot) -> PyResult<Bound<'py, PyDict>> {
+            let dict = PyDict::new(py);
+            dict.set_item("pos", robot.pos)?;
+            dict.set_item("vel", robot.vel)?;
+            Ok(dict)
+        }
+
+        fn from_py(robot: &Bound<'_, PyDict>) -> PyResult<Robot> {
+            Ok(Robot{
+                pos: crate::get_item(robot, "pos")?,
+                vel: crate::get_item(robot, "vel")?,
+            })
+        }
+
+        /// Returns a list of `{"pos": (x, y), "vel": (x, y)}` robots.
+        #[pyfunction]
+        fn parse<'py>(py: Python<'py>, input: &str) -> PyResult<Vec<Bound<'py, PyDict>>> {
+            let robots = day14::parse_input(input.as_bytes()).map_err(crate::value_error)?;
+            robots.iter().map(|robot| to_py(py, robot)).collect()
+        }
+
+        #[pyfunction]
+        fn part_1(robots: Vec<Bound<'_, PyDict>>) -> PyResult<usize> {
+            let robots = robots.iter().map(from_py).collect::<PyResult<Vec<_>>>()?;
+            Ok(day14::solve_part_1(&robots))
+        }
+
+        #[pyfunction]
+        fn part_2(robots: Vec<Bound<'_, PyDict>>) -> PyResult<Option<usize>> {
+            let robots = robots.iter().map(from_py).collect::<PyResult<Vec<_>>>()?;
+            day14::solve_part_2(&robots).map_err(crate::timeout_error)
+        }
+    }
+
+    #[pymodule]
+    mod day15 {
+        use pyo3::prelude::*;
+        use pyo3::types::PyDict;
+        use aoc2024::day15::{self, PuzzleInput};
+
+        /// Returns `{"grid": [...], "robot": (i, j), "moves": [(di, dj), ...]}`.
+        #[pyfunction]
+        fn parse<'py>(py: Python<'py>, input: &str) -> PyResult<Bound<'py, PyDict>> {
+            let input = day15::parse_input(input.as_bytes()).map_err(crate::value_error)?;
+            let dict = PyDict::new(py);
+            dict.set_item("grid", crate::grid_to_py(&input.grid))?;
+            dict.set_item("robot", input.robot)?;
+            dict.set_item("moves", input.moves)?;
+            Ok(dict)
+        }
+
+        fn from_py(input: &Bound<'_, PyDict>) -> PyResult<PuzzleInput> {
+            let input = PuzzleInput{
+                grid: crate::grid_from_py(crate::get_item(input, "grid")?),
+                robot: crate::get_item(input, "robot")?,
+                moves: crate::get_item(input, "moves")?,
+            };
+            input.validate().map_err(crate::value_error)?;
+            Ok(input)
+        }
+
+        #[pyfunction]
+        fn part_1(input: &Bound<'_, PyDict>) -> PyResult<usize> {
+            Ok(day15::solve_part_1(&from_py(input)?))
+        }
+
+        #[pyfunction]
+        fn part_2(input: &Bound<'_, PyDict>) -> PyResult<usize> {
+            Ok(day15::solve_part_2(&from_py(input)?))
+        }
+    }
+
+    #[pymodule]
+    mod day16 {
+        use pyo3::prelude::*;
+        use pyo3::types::PyDict;
+        use aoc2024::day16::{self, PuzzleInput};
+
+        /// Returns `{"maze": [...], "start": (i, j), "end": (i, j)}`.
+        #[pyfunction]
+        fn parse<'py>(py: Python<'py>, input: &str) -> PyResult<Bound<'py, PyDict>> {
+            let input = day16::parse_input(input.as_bytes()).map_err(crate::value_error)?;
+            let dict = PyDict::new(py);
+            dict.set_item("maze", crate::grid_to_py(&input.maze))?;
+            dict.set_item("start", input.start)?;
+            dict.set_item("end", input.end)?;
+            Ok(dict)
+        }
+
+        fn from_py(input: &Bound<'_, PyDict>) -> PyResult<PuzzleInput> {
+            Ok(PuzzleInput{
+                maze: crate::grid_from_py(crate::get_item(input, "maze")?),
+                start: crate::get_item(input, "start")?,
+                end: crate::get_item(input, "end")?,
+            })
+        }
+
+        #[pyfunction]
+        fn part_1(input: &Bound<'_, PyDict>) -> PyResult<Option<usize>> {
+            Ok(day16::solve_part_1(&from_py(input)?))
+        }
+    }
+
+    #[pymodule]
+    mod day17 {
+        use pyo3::prelude::*;
+        use pyo3::types::PyDict;
+        use aoc2024::day17::{self, PuzzleInput};
+
+        /// Returns `{"register_a": ..., "register_b": ..., "register_c": ...,
+        /// "program": [...]}`.
+        #[pyfunction]
+        fn parse<'py>(py: Python<'py>, input: &str) -> PyResult<Bound<'py, PyDict>> {
+            let input = day17::parse_input(input.as_bytes()).map_err(crate::value_error)?;
+            let program: Vec<u32> = input.program.iter().map(|&x| x.into()).collect();
+            let dict = PyDict::new(py);
+            dict.set_item("register_a", input.register_a)?;
+            dict.set_item("register_b", input.register_b)?;
+            dict.set_item("register_c", input.register_c)?;
+            dict.set_item("program", program)?;
+            Ok(dict)
+        }
+
+        fn from_py(input: &Bound<'_, PyDict>) -> PyResult<PuzzleInput> {
+            Ok(PuzzleInput{
+                register_a: crate::get_item(input, "register_a")?,
+                register_b: crate::get_item(input, "register_b")?,
+                register_c: crate::get_item(input, "register_c")?,
+                program: crate::get_item(input, "program")?,
+            })
+        }
+
+        /// Runs the program, returning its comma-separated output.
+        #[pyfunction]
+        fn part_1(input: &Bound<'_, PyDict>) -> PyResult<String> {
+            day17::solve_part_1(&from_py(input)?).map_err(crate::solver_error)
+        }
+    }
+
+    #[pymodule]
+    mod day18 {
+        use pyo3::prelude::*;
+        use aoc2024::day18;
+
+        #[pyfunction]
+        fn parse(input: &str) -> PyResult<Vec<(usize, usize)>> {
+            day18::parse_input(input.as_bytes()).map_err(crate::value_error)
+        }
+
+        #[pyfunction]
+        fn part_1(coords: Vec<(usize, usize)>) -> Option<u64> {
+            day18::solve_part_1(&coords)
+        }
+
+        #[pyfunction]
+        fn part_2(coords: Vec<(usize, usize)>) -> String {
+            day18::solve_part_2(&coords)
+        }
+    }
+
+    #[pymodule]
+    mod day19 {
+        use pyo3::prelude::*;
+        use pyo3::types::PyDict;
+        use aoc2024::day19::{self, PuzzleInput, Trie};
+        use num_bigint::BigUint;
+
+        /// Returns `{"patterns": [...], "designs": [...]}`.
+        #[pyfunction]
+        fn parse<'py>(py: Python<'py>, input: &str) -> PyResult<Bound<'py, PyDict>> {
+            let input = day19::parse_input(input.as_bytes()).map_err(crate::value_error)?;
+            let dict = PyDict::new(py);
+            dict.set_item("patterns", input.trie.words())?;
+            dict.set_item("designs", input.designs)?;
+            Ok(dict)
+        }
+
+        fn from_py(input: &Bound<'_, PyDict>) -> PyResult<PuzzleInput> {
+            let patterns: Vec<String> = crate::get_item(input, "patterns")?;
+            Ok(PuzzleInput{
+                trie: patterns.iter().map(String::as_str).collect(),
+                designs: crate::get_item(input, "designs")?,
+            })
+        }
+
+        #[pyfunction]
+        fn part_1(input: &Bound<'_, PyDict>) -> PyResult<usize> {
+            Ok(day19::solve_part_1(&from_py(input)?))
+        }
+
+        #[pyfunction]
+        fn part_2(input: &Bound<'_, PyDict>) -> PyResult<BigUint> {
+            Ok(day19::solve_part_2(&from_py(input)?))
+        }
+
+        /// How many ways `design` can be made from the given towel patterns.
+        #[pyfunction]
+        fn count_arrangements(design: &str, patterns: Vec<String>) -> BigUint {
+            let trie: Trie = patterns.iter().map(String::as_str).collect();
+            day19::count_arrangements(design, &trie)
+        }
+
+        /// Whether `design` can be made from the given towel patterns.
+        #[pyfunction]
+        fn design_is_possible(design: &str, patterns: Vec<String>) -> bool {
+            let trie: Trie = patterns.iter().map(String::as_str).collect();
+            day19::design_is_possible(design, &trie)
+        }
+    }
+
+    #[pymodule]
+    mod day20 {
+        use pyo3::prelude::*;
+        use pyo3::types::PyDict;
+        use aoc2024::day20::{self, PuzzleInput};
+
+        /// Returns `{"map": [...], "start": (i, j), "end": (i, j)}`.
+        #[pyfunction]
+        fn parse<'py>(py: Python<'py>, input: &str) -> PyResult<Bound<'py, PyDict>> {
+            let input = day20::parse_input(input.as_bytes()).map_err(crate::value_error)?;
+            let dict = PyDict::new(py);
+            dict.set_item("map", crate::grid_to_py(&input.map))?;
+            dict.set_item("start", input.start)?;
+            dict.set_item("end", input.end)?;
+            Ok(dict)
+        }
+
+        fn from_py(input: &Bound<'_, PyDict>) -> PyResult<PuzzleInput> {
+            Ok(PuzzleInput{
+                map: crate::grid_from_py(crate::get_item(input, "map")?),
+                start: crate::get_item(input, "start")?,
+                end: crate::get_item(input, "end")?,
+            })
+        }
+
+        #[pyfunction]
+        fn part_1(input: &Bound<'_, PyDict>) -> PyResult<Option<u64>> {
+            day20::solve_part_1(&from_py(input)?).map_err(crate::timeout_error)
+        }
+    }
+}
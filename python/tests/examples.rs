@@ -0,0 +1,25 @@
+//! Runs `test_examples.py` against the bindings in an embedded interpreter, so
+//! that they are checked by `cargo test` without needing maturin.
+
+use std::error::Error;
+use std::ffi::CString;
+
+use aoc_solvers::aoc_solvers;
+use pyo3::prelude::*;
+
+#[test]
+fn python_examples() -> Result<(), Box<dyn Error>> {
+    pyo3::append_to_inittab!(aoc_solvers);
+    Python::with_gil(|py| {
+        let script = CString::new(include_str!("test_examples.py"))?;
+        let tests = PyModule::from_code(py, &script, c"test_examples.py", c"test_examples")?;
+        let unittest = py.import("unittest")?;
+        let suite = unittest.getattr("defaultTestLoader")?
+            .call_method1("loadTestsFromModule", (tests,))?;
+        let result = unittest.getattr("TextTestRunner")?
+            .call0()?
+            .call_method1("run", (suite,))?;
+        assert!(result.call_method0("wasSuccessful")?.extract::<bool>()?);
+        Ok(())
+    })
+}
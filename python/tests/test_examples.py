@@ -0,0 +1,69 @@
+"""Checks the bindings against the puzzle examples.
+
+Run with `python -m unittest discover tests` after `maturin develop`; the same
+checks also run as part of `cargo test` through an embedded interpreter.
+"""
+
+import unittest
+
+import aoc_solvers
+from aoc_solvers.y2024 import day01, day11, day13, day17, day19
+
+
+class Examples(unittest.TestCase):
+    def test_day01(self):
+        lists = day01.parse("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n")
+        self.assertEqual(lists[0], (3, 4))
+        self.assertEqual(day01.part_1(lists), 11)
+        self.assertEqual(day01.part_2(lists), 31)
+
+    def test_day11(self):
+        stones = day11.parse("125 17")
+        self.assertEqual(stones, [125, 17])
+        self.assertEqual(day11.part_1(stones), 55312)
+        self.assertEqual(day11.count_resulting_stones(125, 6), 7)
+
+    def test_day13(self):
+        machines = day13.parse(
+            "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n\n"
+            "Button A: X+26, Y+66\nButton B: X+67, Y+21\nPrize: X=12748, Y=12176\n\n"
+            "Button A: X+17, Y+86\nButton B: X+84, Y+37\nPrize: X=7870, Y=6450\n\n"
+            "Button A: X+69, Y+23\nButton B: X+27, Y+71\nPrize: X=18641, Y=10279\n"
+        )
+        self.assertEqual(machines[0]["prize"], (8400, 5400))
+        self.assertEqual(day13.tokens_to_win(machines[0]), 280)
+        self.assertIsNone(day13.tokens_to_win(machines[1]))
+        self.assertEqual(day13.part_1(machines), 480)
+
+    def test_day17(self):
+        computer = day17.parse(
+            "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n"
+        )
+        self.assertEqual(computer["program"], [0, 1, 5, 4, 3, 0])
+        self.assertEqual(day17.part_1(computer), "4,6,3,5,6,3,5,2,1,0")
+        computer["register_a"] = 2024
+        self.assertEqual(day17.part_1(computer), "4,2,5,6,7,7,7,7,3,1,0")
+
+    def test_day19(self):
+        towels = day19.parse(
+            "r, wr, b, g, bwu, rb, gb, br\n\n"
+            "brwrr\nbggr\ngbbr\nrrbgbr\nubwu\nbwurrg\nbrgr\nbbrgwb\n"
+        )
+        self.assertEqual(sorted(towels["patterns"]), towels["patterns"])
+        self.assertEqual(day19.part_1(towels), 6)
+        self.assertEqual(day19.part_2(towels), 16)
+        self.assertEqual(day19.count_arrangements("gbbr", towels["patterns"]), 4)
+        self.assertFalse(day19.design_is_possible("ubwu", towels["patterns"]))
+
+    def test_solve(self):
+        self.assertEqual(aoc_solvers.solve(2024, 11, "125 17"), ["55312", "65601038650482"])
+        with self.assertRaises(ValueError):
+            aoc_solvers.solve(2024, 1, "not a location list")
+        with self.assertRaises(ValueError):
+            aoc_solvers.solve(1999, 1, "")
+        with self.assertRaisesRegex(ValueError, "Part 2: No Christmas tree found"):
+            aoc_solvers.solve(2024, 14, "p=0,0 v=0,0\n")
+
+
+if __name__ == "__main__":
+    unittest.main()
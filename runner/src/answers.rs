@@ -0,0 +1,67 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::DayId;
+
+/// The answers of a puzzle, stored in the same `Part N: <answer>` format that
+/// the solutions print.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub parts: [Option<String>; 2],
+}
+
+impl Answers {
+    /// Loads the known answers for a day, which are empty if none were saved.
+    pub fn load(id: DayId) -> io::Result<Self> {
+        Self::load_from(&id.answers_path())
+    }
+
+    /// Loads answers from a file, which are empty if it doesn't exist.
+    pub fn load_from(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Ok(Self::parse(&text)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, id: DayId) -> io::Result<()> {
+        let path = id.answers_path();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    pub fn parse(text: &str) -> Self {
+        let mut answers = Self::default();
+        for line in text.lines() {
+            let Some((label, answer)) = line.split_once(':') else {continue;};
+            let part = match label.trim() {
+                "Part 1" => 0,
+                "Part 2" => 1,
+                _ => continue,
+            };
+            answers.parts[part] = Some(answer.trim().to_string());
+        }
+        answers
+    }
+
+    /// The answer to `part`, which is numbered from 1.
+    pub fn get(&self, part: usize) -> Option<&str> {
+        self.parts.get(part.wrapping_sub(1))?.as_deref()
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, answer) in self.parts.iter().enumerate() {
+            if let Some(answer) = answer {
+                writeln!(f, "Part {}: {}", i + 1, answer)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+//! Cooperative cancellation for searches that aren't guaranteed to finish on
+//! every input. Long-running loops call [`check`], which fails once the
+//! current part has used up its [`Budget`], and the runner then reports that
+//! part as timed out.
+
+use std::cell::Cell;
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// How long each part may run for, as a duration, a number of calls to
+/// [`check`], or both.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Budget {
+    pub time: Option<Duration>,
+    pub steps: Option<u64>,
+}
+
+impl Budget {
+    pub const UNLIMITED: Self = Self{time: None, steps: None};
+
+    pub fn time(time: Duration) -> Self {
+        Self{time: Some(time), steps: None}
+    }
+
+    pub fn steps(steps: u64) -> Self {
+        Self{time: None, steps: Some(steps)}
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedOut;
+
+impl fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "timed out")
+    }
+}
+
+impl Error for TimedOut {}
+
+#[derive(Clone, Copy, Default)]
+struct State {
+    deadline: Option<Instant>,
+    steps_left: Option<u64>,
+    calls: u64,
+    timed_out: bool,
+}
+
+/// Reading the clock on every call would slow down tight loops, so the
+/// deadline is only checked this often.
+const CALLS_PER_CLOCK_CHECK: u64 = 1024;
+
+thread_local! {
+    static STATE: Cell<State> = Cell::new(State::default());
+}
+
+/// Fails if the current part has run out of budget. Once it has failed, it
+/// keeps failing until the part ends.
+pub fn check() -> Result<(), TimedOut> {
+    STATE.with(|cell| {
+        let mut state = cell.get();
+        if !state.timed_out {
+            match &mut state.steps_left {
+                Some(0) => state.timed_out = true,
+                Some(steps) => *steps -= 1,
+                None => {}
+            }
+            state.calls += 1;
+            if state.calls % CALLS_PER_CLOCK_CHECK == 0 {
+                state.timed_out |= state.deadline.is_some_and(|deadline| Instant::now() >= deadline);
+            }
+            cell.set(state);
+        }
+        if state.timed_out {Err(TimedOut)} else {Ok(())}
+    })
+}
+
+/// Runs `f` with the given budget on this thread, returning whether it ran
+/// out.
+pub fn with_budget<T>(budget: Budget, f: impl FnOnce() -> T) -> (T, bool) {
+    struct Restore(State);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            STATE.with(|cell| cell.set(self.0));
+        }
+    }
+
+    let state = State{
+        deadline: budget.time.map(|time| Instant::now() + time),
+        steps_left: budget.steps,
+        ..State::default()
+    };
+    let _restore = Restore(STATE.with(|cell| cell.replace(state)));
+    let result = f();
+    (result, STATE.with(|cell| cell.get().timed_out))
+}
@@ -0,0 +1,60 @@
+use std::fmt;
+use std::str::FromStr;
+use std::path::PathBuf;
+
+use crate::data_dir;
+
+/// Identifies a puzzle by its year and day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DayId {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl DayId {
+    pub const fn new(year: u16, day: u8) -> Self {
+        Self{year, day}
+    }
+
+    /// `inputs/<year>/day<dd>.txt`
+    pub fn input_path(self) -> PathBuf {
+        self.path_in("inputs")
+    }
+
+    /// `answers/<year>/day<dd>.txt`
+    pub fn answers_path(self) -> PathBuf {
+        self.path_in("answers")
+    }
+
+    /// The tracing target of this day's crate, e.g. `aoc2024_day05`. Events
+    /// from its modules have targets below it, e.g. `aoc2024_day06::walk`.
+    pub fn trace_target(self) -> String {
+        format!("aoc{}_day{:02}", self.year, self.day)
+    }
+
+    fn path_in(self, store: &str) -> PathBuf {
+        data_dir()
+            .join(store)
+            .join(self.year.to_string())
+            .join(format!("day{:02}.txt", self.day))
+    }
+}
+
+impl fmt::Display for DayId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{:02}", self.year, self.day)
+    }
+}
+
+/// Parses the `<year>/<day>` format used by [`DayId`]'s `Display` impl.
+impl FromStr for DayId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, day) = s.split_once('/')
+            .ok_or_else(|| format!("Expected <year>/<day>, got {s:?}"))?;
+        let year = year.parse().map_err(|_| format!("Invalid year {year:?}"))?;
+        let day = day.parse().map_err(|_| format!("Invalid day {day:?}"))?;
+        Ok(Self{year, day})
+    }
+}
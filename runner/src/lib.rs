@@ -0,0 +1,268 @@
+//! Shared plumbing for running puzzle solutions.
+//!
+//! Every solution is addressed by a [`DayId`], a (year, day) pair, which also
+//! determines where its puzzle input and known answers are stored on disk.
+
+use std::cell::Cell;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+mod answers;
+pub mod cancel;
+mod id;
+pub mod progress;
+mod puzzle;
+
+pub use answers::Answers;
+pub use cancel::{Budget, TimedOut};
+pub use id::DayId;
+pub use puzzle::{AnswerKind, InputShape, Parameter, Puzzle};
+
+/// A solution for a single day's puzzle.
+pub trait Solution {
+    const ID: DayId;
+    const PUZZLE: Puzzle;
+
+    /// The parsed puzzle input, which may borrow from the raw input text.
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Box<dyn Error>>;
+    fn part_1(input: &Self::Input<'_>) -> String;
+
+    /// Returns `None` if part 2 has not been solved yet.
+    fn part_2(_input: &Self::Input<'_>) -> Option<String> {
+        None
+    }
+
+    /// Other ways of solving the parts, which must give the same answers as
+    /// `part_1` and `part_2`, so that they can be compared with them.
+    fn variants() -> Vec<Variant<Self>> where Self: Sized {
+        Vec::new()
+    }
+}
+
+/// The name of the variant that [`Solution::part_1`] and
+/// [`Solution::part_2`] are.
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// Another way of solving one part of a [`Solution`].
+pub struct Variant<S: Solution> {
+    pub name: &'static str,
+    /// The part it solves, numbered from 1.
+    pub part: usize,
+    pub solve: for<'a> fn(&S::Input<'a>) -> String,
+}
+
+/// A type-erased [`Solution`], so that days from every year can be kept in a
+/// single registry.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub id: DayId,
+    pub puzzle: Puzzle,
+    run: RunFn,
+    run_variant: RunVariantFn,
+    variants: fn() -> Vec<(usize, &'static str)>,
+}
+
+type RunFn = fn(&str, &str, Budget) -> Result<Report, Box<dyn Error>>;
+type RunVariantFn = fn(&str, usize, &str, Budget) -> Result<(Duration, Option<PartReport>), Box<dyn Error>>;
+
+impl Day {
+    pub fn of<S: Solution>() -> Self {
+        Self{id: S::ID, puzzle: S::PUZZLE, run: run::<S>, run_variant: run_variant::<S>, variants: variants::<S>}
+    }
+
+    pub fn run(&self, input: &str) -> Result<Report, Box<dyn Error>> {
+        self.run_with_budget(input, Budget::UNLIMITED)
+    }
+
+    /// Like [`run`](Self::run), but each part that uses up `budget` stops
+    /// early and is reported as timed out.
+    pub fn run_with_budget(&self, input: &str, budget: Budget) -> Result<Report, Box<dyn Error>> {
+        (self.run)(input, DEFAULT_VARIANT, budget)
+    }
+
+    /// Like [`run_with_budget`](Self::run_with_budget), but solves each part
+    /// that has the named variant with it. It is an error if no part has it.
+    pub fn run_with_variant(&self, input: &str, variant: &str, budget: Budget) -> Result<Report, Box<dyn Error>> {
+        if !(1..=self.puzzle.parts.len()).any(|part| self.variants(part).contains(&variant)) {
+            return Err(format!("{} has no variant {variant:?}", self.id).into());
+        }
+        (self.run)(input, variant, budget)
+    }
+
+    /// The names of the ways to solve `part`, starting with
+    /// [`DEFAULT_VARIANT`]. Empty if the day has no such part.
+    pub fn variants(&self, part: usize) -> Vec<&'static str> {
+        if !self.puzzle.has_part(part) {
+            return vec![];
+        }
+        let others = (self.variants)().into_iter().filter(|&(p, _)| p == part).map(|(_, name)| name);
+        [DEFAULT_VARIANT].into_iter().chain(others).collect()
+    }
+
+    /// Solves only `part`, which is numbered from 1, with the same budget
+    /// for it as [`run_with_budget`](Self::run_with_budget). Returns the
+    /// parse time and the part's report, or `None` without parsing the
+    /// input if the day has no such part.
+    pub fn run_part(&self, input: &str, part: usize, budget: Budget) -> Result<(Duration, Option<PartReport>), Box<dyn Error>> {
+        self.run_variant(input, part, DEFAULT_VARIANT, budget)
+    }
+
+    /// Like [`run_part`](Self::run_part), but with the named variant. Returns
+    /// `None` without parsing the input if the part has no such variant.
+    pub fn run_variant(&self, input: &str, part: usize, variant: &str, budget: Budget) -> Result<(Duration, Option<PartReport>), Box<dyn Error>> {
+        if !self.variants(part).contains(&variant) {
+            return Ok((Duration::ZERO, None));
+        }
+        (self.run_variant)(input, part, variant, budget)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub parse_time: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub answer: String,
+    pub time: Duration,
+    /// The part ran out of budget, so `answer` is meaningless.
+    pub timed_out: bool,
+    /// The solver [failed](fail) on the input, so `answer` is the error
+    /// message rather than an answer.
+    pub failed: bool,
+}
+
+impl PartReport {
+    fn new(answer: String, time: Duration, timed_out: bool, failed: bool) -> Self {
+        let answer = if timed_out {TimedOut.to_string()} else {answer};
+        Self{answer, time, timed_out, failed: failed && !timed_out}
+    }
+
+    /// Whether `answer` is an answer: the part neither timed out nor failed.
+    pub fn is_answer(&self) -> bool {
+        !self.timed_out && !self.failed
+    }
+}
+
+impl Report {
+    pub fn answers(&self) -> Answers {
+        let mut answers = Answers::default();
+        for (part, report) in self.parts.iter().enumerate() {
+            if report.is_answer() {
+                answers.parts[part] = Some(report.answer.clone());
+            }
+        }
+        answers
+    }
+}
+
+fn run<S: Solution>(input: &str, variant: &str, budget: Budget) -> Result<Report, Box<dyn Error>> {
+    let _span = tracing::info_span!("solve", day = %S::ID).entered();
+    let (parsed, parse_time) = parse::<S>(input)?;
+
+    let mut parts = vec![];
+    for part in 1..=S::PUZZLE.parts.len() {
+        parts.extend(solve_part::<S>(&parsed, part, variant, budget)?);
+    }
+    Ok(Report{parse_time, parts})
+}
+
+fn run_variant<S: Solution>(input: &str, part: usize, variant: &str, budget: Budget) -> Result<(Duration, Option<PartReport>), Box<dyn Error>> {
+    let _span = tracing::info_span!("solve", day = %S::ID).entered();
+    let (parsed, parse_time) = parse::<S>(input)?;
+    Ok((parse_time, solve_part::<S>(&parsed, part, variant, budget)?))
+}
+
+fn variants<S: Solution>() -> Vec<(usize, &'static str)> {
+    S::variants().iter().map(|variant| (variant.part, variant.name)).collect()
+}
+
+fn parse<S: Solution>(input: &str) -> Result<(S::Input<'_>, Duration), Box<dyn Error>> {
+    let start = Instant::now();
+    let parsed = tracing::info_span!("parse").in_scope(|| catch_panic("Parsing", || S::parse(input)))??;
+    Ok((parsed, start.elapsed()))
+}
+
+/// Solves `part` with the named variant, or with the default one if the part
+/// has no such variant. Returns `None` if the part isn't solved.
+fn solve_part<S: Solution>(parsed: &S::Input<'_>, part: usize, variant: &str, budget: Budget) -> Result<Option<PartReport>, Box<dyn Error>> {
+    let other = S::variants().into_iter().find(|other| other.part == part && other.name == variant);
+    let name = other.as_ref().map_or(DEFAULT_VARIANT, |other| other.name);
+    let solve = || match (&other, part) {
+        (Some(other), _) => Some((other.solve)(parsed)),
+        (None, 1) => Some(S::part_1(parsed)),
+        (None, _) => S::part_2(parsed),
+    };
+
+    let start = Instant::now();
+    FAILED.with(|failed| failed.set(false));
+    let (answer, timed_out) = tracing::info_span!("part", n = part, variant = name)
+        .in_scope(|| catch_panic(&format!("Part {part}"), || cancel::with_budget(budget, solve)))?;
+    let failed = FAILED.with(|failed| failed.replace(false));
+    Ok(answer.map(|answer| PartReport::new(answer, start.elapsed(), timed_out, failed)))
+}
+
+thread_local! {
+    static FAILED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Marks the part being solved on this thread as failed, for a solver that
+/// can't give an answer for its input, and returns `err`'s message to return
+/// in place of the answer. Callers can then tell it from an answer, and don't
+/// save it.
+pub fn fail(err: impl fmt::Display) -> String {
+    FAILED.with(|failed| failed.set(true));
+    err.to_string()
+}
+
+/// The error for a parser or solver that panicked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panicked {
+    /// What was running, e.g. "Parsing" or "Part 2".
+    pub what: String,
+    pub message: String,
+}
+
+impl fmt::Display for Panicked {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} panicked: {}", self.what, self.message)
+    }
+}
+
+impl Error for Panicked {}
+
+/// Runs `f`, turning a panic into a [`Panicked`] error, so that a solver that
+/// panics on some input (or overflows, when built with overflow checks)
+/// doesn't take down the whole run.
+fn catch_panic<T>(what: &str, f: impl FnOnce() -> T) -> Result<T, Box<dyn Error>> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        let message = payload.downcast_ref::<&str>().copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("unknown cause");
+        Panicked{what: what.to_string(), message: message.to_string()}.into()
+    })
+}
+
+/// Entry point for a single day's binary: solves the puzzle given on stdin.
+pub fn main<S: Solution>() -> Result<(), Box<dyn Error>> {
+    let input = io::read_to_string(io::stdin().lock())?;
+    let report = Day::of::<S>().run(&input)?;
+    print!("{}", report.answers());
+    Ok(())
+}
+
+/// Root directory of the `inputs/` and `answers/` stores. Defaults to the
+/// workspace root, and can be overridden with `AOC_DATA_DIR`.
+pub fn data_dir() -> PathBuf {
+    std::env::var_os("AOC_DATA_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/..")))
+}
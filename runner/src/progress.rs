@@ -0,0 +1,35 @@
+//! Progress reports from solvers that take a long time on some inputs. They
+//! call [`report`] with how much of their work is done, and whoever runs them
+//! decides whether to show it, with [`with_reporter`]. Reports go nowhere by
+//! default.
+
+use std::cell::RefCell;
+
+type Reporter = Box<dyn FnMut(u64, u64)>;
+
+thread_local! {
+    static REPORTER: RefCell<Option<Reporter>> = const {RefCell::new(None)};
+}
+
+/// Reports that `done` out of `total` units of work have been done.
+pub fn report(done: u64, total: u64) {
+    REPORTER.with(|reporter| {
+        if let Some(reporter) = reporter.borrow_mut().as_mut() {
+            reporter(done, total);
+        }
+    });
+}
+
+/// Runs `f`, passing the progress it reports on this thread to `reporter`.
+pub fn with_reporter<T>(reporter: impl FnMut(u64, u64) + 'static, f: impl FnOnce() -> T) -> T {
+    struct Restore(Option<Reporter>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            REPORTER.with(|reporter| *reporter.borrow_mut() = self.0.take());
+        }
+    }
+
+    let previous = REPORTER.with(|cell| cell.borrow_mut().replace(Box::new(reporter)));
+    let _restore = Restore(previous);
+    f()
+}
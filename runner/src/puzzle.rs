@@ -0,0 +1,68 @@
+use std::fmt;
+
+/// What is known about a day's puzzle, apart from how to solve it.
+#[derive(Debug, Clone, Copy)]
+pub struct Puzzle {
+    pub title: &'static str,
+    /// The kind of answer each implemented part gives, starting with part 1.
+    pub parts: &'static [AnswerKind],
+    /// Constants from the puzzle text that the solution is written for.
+    pub parameters: &'static [Parameter],
+    /// How the puzzle input is laid out.
+    pub input: InputShape,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnswerKind {
+    Integer,
+    /// Anything else, e.g. a list of numbers or a coordinate.
+    Text,
+}
+
+impl fmt::Display for AnswerKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            AnswerKind::Integer => "integer",
+            AnswerKind::Text => "text",
+        })
+    }
+}
+
+/// How a puzzle input is laid out, so that it can be cut down without
+/// breaking its structure, e.g. when minimising an input that a solver fails
+/// on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputShape {
+    /// Lines that are only kept or removed whole.
+    Lines,
+    /// Lines of items split by `separator`, or of single characters if it is
+    /// empty. Lines and items can be removed.
+    Items{separator: &'static str},
+    /// A grid of characters, whose rows and columns can be removed.
+    Grid,
+    /// Blocks of lines separated by blank lines, which are only removed whole.
+    Blocks,
+    /// Sections separated by a blank line, each with its own shape, which
+    /// are always kept.
+    Sections(&'static [InputShape]),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Parameter {
+    pub name: &'static str,
+    pub value: u64,
+    pub description: &'static str,
+}
+
+impl fmt::Display for Parameter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}={}", self.name, self.value)
+    }
+}
+
+impl Puzzle {
+    /// Whether `part`, which is numbered from 1, is implemented.
+    pub fn has_part(&self, part: usize) -> bool {
+        (1..=self.parts.len()).contains(&part)
+    }
+}
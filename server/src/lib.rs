@@ -0,0 +1,192 @@
+//! An HTTP service for the solutions, so that other tools can get answers
+//! without running a binary per day.
+//!
+//! - `GET /health` answers `{"status": "ok"}`.
+//! - `POST /solve/{year}/{day}/{part}` takes the raw puzzle input as the body
+//!   and answers with the part's answer and timings. A `timeout=SECS` query
+//!   parameter lowers the server's time limit for that request. When
+//!   [`Config::max_solves`] parts are already being solved, it answers 503.
+
+use std::io::{Cursor, Read};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+use runner::{Budget, DayId};
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+/// Inputs larger than this are rejected, as no puzzle input comes close.
+pub const MAX_INPUT_LEN: u64 = 16 << 20;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    /// The most time a request may spend on solving its part.
+    pub timeout: Duration,
+    /// The most parts that may be solved at once, counting those whose
+    /// requests have timed out but that haven't stopped yet.
+    pub max_solves: usize,
+}
+
+/// Answers requests on `threads` threads, for as long as the server runs.
+pub fn serve(server: Arc<Server>, config: Config, threads: usize) {
+    let slots = Arc::new(Slots{max: config.max_solves, taken: AtomicUsize::new(0)});
+    let workers: Vec<_> = (0..threads.max(1))
+        .map(|_| {
+            let server = Arc::clone(&server);
+            let slots = Arc::clone(&slots);
+            thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    respond(request, config, &slots);
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        let _ = worker.join();
+    }
+}
+
+/// Counts the parts being solved, so that there are never more than `max`.
+struct Slots {
+    max: usize,
+    taken: AtomicUsize,
+}
+
+impl Slots {
+    /// Takes a slot, or returns `None` if they are all taken.
+    fn take(self: &Arc<Self>) -> Option<Slot> {
+        self.taken.fetch_update(Ordering::AcqRel, Ordering::Acquire, |taken| (taken < self.max).then_some(taken + 1)).ok()?;
+        Some(Slot(Arc::clone(self)))
+    }
+}
+
+/// A taken slot, which is given back when dropped.
+struct Slot(Arc<Slots>);
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.taken.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+fn respond(mut request: Request, config: Config, slots: &Arc<Slots>) {
+    let response = handle(&mut request, config, slots);
+    // The client may have gone away, and there is no one else to tell.
+    let _ = request.respond(response);
+}
+
+fn handle(request: &mut Request, config: Config, slots: &Arc<Slots>) -> Response<Cursor<Vec<u8>>> {
+    let url = request.url().to_string();
+    let (path, query) = url.split_once('?').unwrap_or((&url, ""));
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    match (request.method(), segments.as_slice()) {
+        (Method::Get, ["health"]) => json_response(200, json!({"status": "ok"})),
+        (Method::Post, ["solve", year, day, part]) => {
+            let (Ok(year), Ok(day), Ok(part)) = (year.parse(), day.parse(), part.parse()) else {
+                return error(404, format!("No such puzzle: {path}"));
+            };
+            let timeout = match request_timeout(query, config.timeout) {
+                Ok(timeout) => timeout,
+                Err(err) => return error(400, err),
+            };
+
+            let mut input = String::new();
+            let mut reader = request.as_reader().take(MAX_INPUT_LEN + 1);
+            if reader.read_to_string(&mut input).is_err() {
+                return error(400, "Could not read the input as UTF-8 text");
+            }
+            if input.len() as u64 > MAX_INPUT_LEN {
+                return error(413, format!("The input is longer than {MAX_INPUT_LEN} bytes"));
+            }
+            solve(DayId::new(year, day), part, input, timeout, slots)
+        }
+        (_, ["health"]) | (_, ["solve", _, _, _]) => error(405, format!("{} is not allowed on {path}", request.method())),
+        _ => error(404, format!("Not found: {path}")),
+    }
+}
+
+/// The time limit for a request: the `timeout` query parameter if it is
+/// given and lower than `max`, or else `max`.
+fn request_timeout(query: &str, max: Duration) -> Result<Duration, String> {
+    let param = query.split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == "timeout");
+    let Some((_, secs)) = param else {
+        return Ok(max);
+    };
+    let timeout = secs.parse::<f64>().ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .ok_or_else(|| format!("Invalid timeout: {secs}"))?;
+    Ok(timeout.min(max))
+}
+
+/// Solves the part on a thread of its own, so that the request can be
+/// answered when the time runs out even if the day doesn't check its budget
+/// often enough. Such a day keeps its thread, and its slot, until it stops.
+fn solve(id: DayId, part: usize, input: String, timeout: Duration, slots: &Arc<Slots>) -> Response<Cursor<Vec<u8>>> {
+    let Some(day) = aoc::find(id) else {
+        return error(404, format!("No solution for {id}"));
+    };
+    let Some(slot) = slots.take() else {
+        return error(503, "Too many parts are being solved, try again later");
+    };
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _slot = slot;
+        let result = day.run_part(&input, part, Budget::time(timeout)).map_err(|err| err.to_string());
+        // The request may have timed out already, and then no one is waiting.
+        let _ = sender.send(result);
+    });
+    let (parse_time, report) = match receiver.recv_timeout(timeout) {
+        Ok(Ok(result)) => result,
+        Ok(Err(err)) => return error(422, err),
+        Err(_) => return timed_out(id, part, timeout, Duration::ZERO, timeout),
+    };
+    let Some(report) = report else {
+        return error(404, format!("No solution for {id} part {part}"));
+    };
+
+    if report.timed_out {
+        return timed_out(id, part, timeout, parse_time, report.time);
+    }
+    if report.failed {
+        return error(422, report.answer);
+    }
+    let mut body = timings(id, part, parse_time, report.time);
+    body["answer"] = Value::from(report.answer);
+    json_response(200, body)
+}
+
+fn timed_out(id: DayId, part: usize, timeout: Duration, parse_time: Duration, time: Duration) -> Response<Cursor<Vec<u8>>> {
+    let mut body = timings(id, part, parse_time, time);
+    body["error"] = Value::from(format!("Timed out after {timeout:?}"));
+    json_response(504, body)
+}
+
+fn timings(id: DayId, part: usize, parse_time: Duration, time: Duration) -> Value {
+    json!({
+        "year": id.year,
+        "day": id.day,
+        "part": part,
+        "parse_time_ms": millis(parse_time),
+        "time_ms": millis(time),
+    })
+}
+
+fn millis(time: Duration) -> f64 {
+    time.as_secs_f64() * 1000.0
+}
+
+fn error(status: u16, message: impl Into<String>) -> Response<Cursor<Vec<u8>>> {
+    json_response(status, json!({"error": message.into()}))
+}
+
+fn json_response(status: u16, body: Value) -> Response<Cursor<Vec<u8>>> {
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    Response::from_string(body.to_string())
+        .with_status_code(status)
+        .with_header(content_type)
+}
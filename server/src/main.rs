@@ -0,0 +1,45 @@
+use std::error::Error;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use aoc_server::Config;
+use clap::Parser;
+use tiny_http::Server;
+
+#[derive(Parser)]
+#[command(about = "Serves the Advent of Code solutions over HTTP")]
+struct Cli {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:3000")]
+    addr: String,
+    /// Give up on a request's part after this many seconds
+    #[arg(long, value_name = "SECS", default_value = "10", value_parser = parse_seconds)]
+    timeout: Duration,
+    /// Number of requests to handle at once [default: number of CPUs]
+    #[arg(long)]
+    threads: Option<usize>,
+    /// Number of parts to solve at once, counting those that have timed out
+    /// but not stopped yet [default: number of threads]
+    #[arg(long)]
+    max_solves: Option<usize>,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let cli = Cli::parse();
+    let threads = match cli.threads {
+        Some(threads) => threads,
+        None => thread::available_parallelism()?.get(),
+    };
+
+    let server = Server::http(&cli.addr).map_err(|err| err.to_string())?;
+    eprintln!("Listening on http://{}", server.server_addr());
+    let config = Config{timeout: cli.timeout, max_solves: cli.max_solves.unwrap_or(threads)};
+    aoc_server::serve(Arc::new(server), config, threads);
+    Ok(())
+}
+
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    let secs: f64 = s.parse().map_err(|_| format!("invalid number of seconds: {s}"))?;
+    Duration::try_from_secs_f64(secs).map_err(|err| err.to_string())
+}
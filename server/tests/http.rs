@@ -0,0 +1,159 @@
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::sync::{Arc, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_server::Config;
+use serde_json::Value;
+use tiny_http::Server;
+
+const DAY01: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+/// Starts a server on a free port.
+fn start(config: Config, threads: usize) -> SocketAddr {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let addr = server.server_addr().to_ip().unwrap();
+    thread::spawn(move || aoc_server::serve(Arc::new(server), config, threads));
+    addr
+}
+
+/// Starts one server for most of the tests to share.
+fn server_addr() -> SocketAddr {
+    static ADDR: OnceLock<SocketAddr> = OnceLock::new();
+    *ADDR.get_or_init(|| start(Config{timeout: Duration::from_secs(5), max_solves: 8}, 4))
+}
+
+/// Sends a request to the shared server, and returns the status code and
+/// JSON body of the response.
+fn request(method: &str, path: &str, body: &str) -> (u16, Value) {
+    request_to(server_addr(), method, path, body)
+}
+
+fn request_to(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, Value) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(
+        stream,
+        "{method} {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{body}",
+        body.len(),
+    ).unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+    assert!(head.contains("Content-Type: application/json"), "{head}");
+    (status, serde_json::from_str(body).unwrap())
+}
+
+#[test]
+fn health() {
+    let (status, body) = request("GET", "/health", "");
+    assert_eq!(status, 200);
+    assert_eq!(body["status"], "ok");
+}
+
+#[test]
+fn solves_each_part() {
+    let (status, body) = request("POST", "/solve/2024/1/1", DAY01);
+    assert_eq!(status, 200);
+    assert_eq!(body["answer"], "11");
+    assert_eq!(body["year"], 2024);
+    assert_eq!(body["day"], 1);
+    assert_eq!(body["part"], 1);
+    assert!(body["parse_time_ms"].as_f64().unwrap() >= 0.0);
+    assert!(body["time_ms"].as_f64().unwrap() >= 0.0);
+
+    let (status, body) = request("POST", "/solve/2024/01/2", DAY01);
+    assert_eq!(status, 200);
+    assert_eq!(body["answer"], "31");
+}
+
+#[test]
+fn unknown_puzzles_are_not_found() {
+    assert_eq!(request("POST", "/solve/2024/25/1", DAY01).0, 404);
+    assert_eq!(request("POST", "/solve/2024/1/3", DAY01).0, 404);
+    assert_eq!(request("POST", "/solve/2024/one/1", DAY01).0, 404);
+    assert_eq!(request("GET", "/answers", "").0, 404);
+
+    let program = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n";
+    let (status, body) = request("POST", "/solve/2024/17/2", program);
+    assert_eq!(status, 404);
+    assert_eq!(body["error"], "No solution for 2024/17 part 2");
+}
+
+#[test]
+fn wrong_method_is_not_allowed() {
+    assert_eq!(request("GET", "/solve/2024/1/1", "").0, 405);
+    assert_eq!(request("POST", "/health", "").0, 405);
+}
+
+#[test]
+fn invalid_input_is_unprocessable() {
+    let (status, body) = request("POST", "/solve/2024/1/1", "3 four\n");
+    assert_eq!(status, 422);
+    assert!(body["error"].is_string());
+}
+
+#[test]
+fn inputs_without_an_answer_are_unprocessable() {
+    let (status, body) = request("POST", "/solve/2024/16/1", "#####\n#S#E#\n#####\n");
+    assert_eq!(status, 422);
+    assert_eq!(body["error"], "No answer found");
+    assert!(body.get("answer").is_none());
+}
+
+#[test]
+fn timeouts() {
+    let looping_guard = ".#..\n.^.#\n#...\n..#.\n";
+    let (status, body) = request("POST", "/solve/2024/6/1?timeout=0.05", looping_guard);
+    assert_eq!(status, 504);
+    assert_eq!(body["error"], "Timed out after 50ms");
+    assert!(body.get("answer").is_none());
+
+    assert_eq!(request("POST", "/solve/2024/1/1?timeout=soon", DAY01).0, 400);
+}
+
+/// A map that day 20 takes much longer than the server's time limit on, as
+/// it searches the whole map again for every wall.
+fn slow_map() -> String {
+    let size = 201;
+    let mut rows: Vec<String> = (0..size)
+        .map(|i| (0..size).map(|j| if i % 2 == 1 && j % 2 == 1 {'#'} else {'.'}).collect())
+        .collect();
+    rows[0].replace_range(..1, "S");
+    rows[size - 1].replace_range(size - 1.., "E");
+    rows.join("\n") + "\n"
+}
+
+#[test]
+fn slow_parts_stop_when_they_time_out() {
+    let addr = start(Config{timeout: Duration::from_secs(5), max_solves: 1}, 2);
+    let began = Instant::now();
+    let (status, body) = request_to(addr, "POST", "/solve/2024/20/1?timeout=0.05", &slow_map());
+    assert_eq!(status, 504);
+    assert_eq!(body["error"], "Timed out after 50ms");
+    assert!(began.elapsed() < Duration::from_secs(5));
+
+    // The part gives its slot back once it stops, well before it would have
+    // finished.
+    let (status, body) = loop {
+        let (status, body) = request_to(addr, "POST", "/solve/2024/1/1", DAY01);
+        if status != 503 || began.elapsed() > Duration::from_secs(5) {
+            break (status, body);
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+    assert_eq!(status, 200, "{body}");
+}
+
+#[test]
+fn solves_beyond_the_limit_are_unavailable() {
+    let addr = start(Config{timeout: Duration::from_secs(5), max_solves: 1}, 2);
+    let slow = thread::spawn(move || request_to(addr, "POST", "/solve/2024/20/1?timeout=1", &slow_map()).0);
+    thread::sleep(Duration::from_millis(300));
+    let (status, body) = request_to(addr, "POST", "/solve/2024/1/1", DAY01);
+    assert_eq!(status, 503);
+    assert_eq!(body["error"], "Too many parts are being solved, try again later");
+    assert_eq!(slow.join().unwrap(), 504);
+}
@@ -0,0 +1,162 @@
+//! Interactive step-by-step views of the puzzles that are simulations. Each
+//! view drives the same simulation code that the solver uses, so what is shown
+//! is exactly what the solver computes.
+
+use std::error::Error;
+use std::ops::Range;
+
+use runner::DayId;
+
+pub mod sims;
+mod ui;
+
+/// Interval between the saved states that stepping back replays from.
+const CHECKPOINT_INTERVAL: usize = 1000;
+
+/// A simulation that can be advanced one step at a time.
+pub trait Simulation: Clone {
+    /// Conditions that can be run until, checked after each step.
+    fn conditions() -> Vec<Condition<Self>>;
+
+    /// Advances one step. Returns false, leaving the state unchanged, if the
+    /// simulation has finished.
+    fn step(&mut self) -> bool;
+
+    /// Draws the current state.
+    fn frame(&self) -> Frame;
+}
+
+/// A named condition on the state of a simulation.
+pub struct Condition<S> {
+    pub name: &'static str,
+    pub holds: fn(&S) -> bool,
+}
+
+/// A drawing of the state of a simulation: a status line, and some lines of
+/// text with parts of them highlighted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Frame {
+    pub status: String,
+    pub lines: Vec<String>,
+    /// Line numbers and the column ranges to highlight within them.
+    pub highlights: Vec<(usize, Range<usize>)>,
+}
+
+/// Moves a simulation forward and back. Going back restarts from the last
+/// checkpoint before the target step and replays the steps after it.
+pub struct Stepper<S> {
+    checkpoints: Vec<S>,
+    current: S,
+    step: usize,
+    finished: bool,
+}
+
+impl<S: Simulation> Stepper<S> {
+    pub fn new(sim: S) -> Self {
+        Self{checkpoints: vec![sim.clone()], current: sim, step: 0, finished: false}
+    }
+
+    pub fn state(&self) -> &S {
+        &self.current
+    }
+
+    /// The number of steps taken from the initial state.
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// Whether the simulation has been found to have no more steps.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Takes one step. Returns false if the simulation has finished.
+    pub fn forward(&mut self) -> bool {
+        if self.finished {
+            return false;
+        }
+        if !self.current.step() {
+            self.finished = true;
+            return false;
+        }
+
+        self.step += 1;
+        if self.step == self.checkpoints.len() * CHECKPOINT_INTERVAL {
+            self.checkpoints.push(self.current.clone());
+        }
+        true
+    }
+
+    /// Undoes one step. Returns false if at the initial state.
+    pub fn back(&mut self) -> bool {
+        if self.step == 0 {
+            return false;
+        }
+        self.go_to(self.step - 1);
+        true
+    }
+
+    /// Goes to step `target`, or to the last step if the simulation finishes
+    /// first. Returns the step reached.
+    pub fn go_to(&mut self, target: usize) -> usize {
+        if target < self.step {
+            let checkpoint = (target / CHECKPOINT_INTERVAL).min(self.checkpoints.len() - 1);
+            self.current = self.checkpoints[checkpoint].clone();
+            self.step = checkpoint * CHECKPOINT_INTERVAL;
+            self.finished = false;
+        }
+        while self.step < target && self.forward() {}
+        self.step
+    }
+
+    /// Moves `n` steps forward, or back if `n` is negative.
+    pub fn jump(&mut self, n: isize) -> usize {
+        self.go_to(self.step.saturating_add_signed(n))
+    }
+
+    /// Steps until `condition` holds, for at most `max_steps` steps. Returns
+    /// false if the simulation finished or ran out of steps first.
+    pub fn run_until(&mut self, condition: &Condition<S>, max_steps: usize) -> bool {
+        for _ in 0..max_steps {
+            if !self.forward() {
+                return false;
+            }
+            if (condition.holds)(&self.current) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Steps until the simulation finishes, for at most `max_steps` steps.
+    pub fn run_to_end(&mut self, max_steps: usize) -> bool {
+        for _ in 0..max_steps {
+            if !self.forward() {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// Opens the interactive view of a day's simulation in the terminal.
+pub fn open(id: DayId, input: &str) -> Result<(), Box<dyn Error>> {
+    let title = format!("{id}");
+    match (id.year, id.day) {
+        (2024, 6) => {
+            let grid = grid::rows(input);
+            let patrol = sims::Patrol::new(&grid).ok_or("No guard found in input")?;
+            ui::run(&title, patrol)?;
+        }
+        (2024, 15) => {
+            let input = aoc2024::day15::parse_input(input.as_bytes())?;
+            ui::run(&title, sims::Warehouse::new(&input))?;
+        }
+        (2024, 17) => {
+            let input = aoc2024::day17::parse_input(input.as_bytes())?;
+            ui::run(&title, sims::Program::new(&input))?;
+        }
+        _ => return Err(format!("No stepper for {id}; only 2024/06, 2024/15 and 2024/17 can be stepped through").into()),
+    }
+    Ok(())
+}
@@ -0,0 +1,246 @@
+//! The simulations that can be stepped through, built on the solvers' own
+//! simulation code.
+
+use std::collections::HashSet;
+
+use aoc2024::day06::WalkIterator;
+use aoc2024::day15::{self, WideWarehouse};
+use aoc2024::day17::{self, Computer};
+use geometry::Direction;
+
+use crate::{Condition, Frame, Simulation};
+
+/// Day 6: the guard's patrol, one position at a time.
+#[derive(Clone)]
+pub struct Patrol<'a> {
+    grid: &'a [&'a [u8]],
+    walk: WalkIterator<'a>,
+    /// The guard's position and direction, or `None` once it has left.
+    pub guard: Option<(usize, usize, Direction)>,
+    seen: HashSet<(usize, usize, Direction)>,
+    turned: bool,
+    looped: bool,
+}
+
+impl<'a> Patrol<'a> {
+    /// Starts the patrol, or returns `None` if there is no guard.
+    pub fn new(grid: &'a [&'a [u8]]) -> Option<Self> {
+        let start = grid::find(grid, &b'^')?;
+        let mut walk = WalkIterator::new(grid, start, Direction::Up);
+        let guard = walk.next();
+        let seen = guard.into_iter().collect();
+        Some(Self{grid, walk, guard, seen, turned: false, looped: false})
+    }
+
+    /// The number of distinct cells the guard has been on.
+    pub fn visited(&self) -> usize {
+        self.visited_cells().len()
+    }
+
+    fn visited_cells(&self) -> HashSet<(usize, usize)> {
+        self.seen.iter().map(|&(i, j, _)| (i, j)).collect()
+    }
+}
+
+impl Simulation for Patrol<'_> {
+    fn conditions() -> Vec<Condition<Self>> {
+        vec![
+            Condition{name: "the guard turns", holds: |s| s.turned},
+            Condition{name: "the guard starts repeating itself", holds: |s| s.looped},
+        ]
+    }
+
+    fn step(&mut self) -> bool {
+        let Some((_, _, dir)) = self.guard else {
+            return false;
+        };
+        self.guard = self.walk.next();
+        self.turned = self.guard.is_some_and(|(_, _, new_dir)| new_dir != dir);
+        self.looped = self.guard.is_some_and(|state| !self.seen.insert(state));
+        true
+    }
+
+    fn frame(&self) -> Frame {
+        let visited = self.visited_cells();
+        let mut lines: Vec<Vec<u8>> = self.grid.iter().map(|row| row.to_vec()).collect();
+        for &(i, j) in &visited {
+            lines[i][j] = b'X';
+        }
+
+        let mut highlights = vec![];
+        let status = match self.guard {
+            Some((i, j, dir)) => {
+                lines[i][j] = match dir {
+                    Direction::Up => b'^',
+                    Direction::Right => b'>',
+                    Direction::Down => b'v',
+                    Direction::Left => b'<',
+                };
+                highlights.push((i, j..j+1));
+                let looping = if self.looped {", walking in a loop"} else {""};
+                format!("Guard at ({i}, {j}) facing {dir:?}, {} cells visited{looping}", visited.len())
+            }
+            None => format!("Guard has left the map, {} cells visited", visited.len()),
+        };
+
+        let lines = lines.into_iter().map(|line| String::from_utf8_lossy(&line).into_owned()).collect();
+        Frame{status, lines, highlights}
+    }
+}
+
+/// Day 15, part 2: the robot's moves in the wide warehouse.
+#[derive(Clone)]
+pub struct Warehouse<'a> {
+    input: &'a day15::PuzzleInput,
+    pub warehouse: WideWarehouse,
+    /// The number of moves made so far.
+    pub moves: usize,
+    pushed: bool,
+    blocked: bool,
+}
+
+impl<'a> Warehouse<'a> {
+    pub fn new(input: &'a day15::PuzzleInput) -> Self {
+        let warehouse = WideWarehouse::new(input);
+        Self{input, warehouse, moves: 0, pushed: false, blocked: false}
+    }
+}
+
+fn move_symbol(d: (i32, i32)) -> char {
+    match d {
+        (-1, 0) => '^',
+        (1, 0) => 'v',
+        (0, -1) => '<',
+        _ => '>',
+    }
+}
+
+impl Simulation for Warehouse<'_> {
+    fn conditions() -> Vec<Condition<Self>> {
+        vec![
+            Condition{name: "the robot pushes a box", holds: |s| s.pushed},
+            Condition{name: "the robot is blocked", holds: |s| s.blocked},
+        ]
+    }
+
+    fn step(&mut self) -> bool {
+        let Some(&d) = self.input.moves.get(self.moves) else {
+            return false;
+        };
+        let robot = self.warehouse.robot;
+        let destination = (robot.0 + d.0, robot.1 + d.1);
+        let in_front = self.warehouse.grid[destination.0 as usize][destination.1 as usize];
+
+        self.warehouse.move_robot(d);
+        self.moves += 1;
+        self.blocked = self.warehouse.robot == robot;
+        self.pushed = !self.blocked && b"[]".contains(&in_front);
+        true
+    }
+
+    fn frame(&self) -> Frame {
+        let total = self.input.moves.len();
+        let next = match self.input.moves.get(self.moves) {
+            Some(&d) => format!("next {}", move_symbol(d)),
+            None => String::from("done"),
+        };
+        let gps = day15::gps_sum(&self.warehouse.grid, b'[');
+        let status = format!("Move {} of {total}, {next}, GPS sum {gps}", self.moves);
+
+        let lines = self.warehouse.grid.iter()
+            .map(|row| String::from_utf8_lossy(row).into_owned())
+            .collect();
+        let (i, j) = self.warehouse.robot;
+        let highlights = vec![(i as usize, j as usize .. j as usize + 1)];
+        Frame{status, lines, highlights}
+    }
+}
+
+/// Day 17, part 1: the 3-bit computer, one instruction at a time.
+#[derive(Clone)]
+pub struct Program<'a> {
+    input: &'a day17::PuzzleInput,
+    pub computer: Computer,
+    output: bool,
+    jumped: bool,
+}
+
+impl<'a> Program<'a> {
+    pub fn new(input: &'a day17::PuzzleInput) -> Self {
+        let computer = Computer::new(input);
+        Self{input, computer, output: false, jumped: false}
+    }
+}
+
+fn disassemble(opcode: u8, operand: u8) -> String {
+    let combo = match operand {
+        0..=3 => operand.to_string(),
+        4 => String::from("A"),
+        5 => String::from("B"),
+        6 => String::from("C"),
+        _ => String::from("?"),
+    };
+    match opcode {
+        0 => format!("adv {combo}"),
+        1 => format!("bxl {operand}"),
+        2 => format!("bst {combo}"),
+        3 => format!("jnz {operand}"),
+        4 => String::from("bxc"),
+        5 => format!("out {combo}"),
+        6 => format!("bdv {combo}"),
+        7 => format!("cdv {combo}"),
+        _ => String::from("???"),
+    }
+}
+
+impl Simulation for Program<'_> {
+    fn conditions() -> Vec<Condition<Self>> {
+        vec![
+            Condition{name: "the program outputs a value", holds: |s| s.output},
+            Condition{name: "the program jumps", holds: |s| s.jumped},
+        ]
+    }
+
+    fn step(&mut self) -> bool {
+        let ip = self.computer.ip;
+        let out = self.computer.out.len();
+        // Parsing rejects programs that could reach an invalid instruction,
+        // so this only stops when the program halts.
+        if !self.computer.step(&self.input.program).unwrap_or(false) {
+            return false;
+        }
+        self.output = self.computer.out.len() != out;
+        self.jumped = self.computer.ip != ip + 2;
+        true
+    }
+
+    fn frame(&self) -> Frame {
+        let computer = &self.computer;
+        let program = &self.input.program;
+        let status = if computer.ip < program.len() {
+            format!("ip = {}", computer.ip)
+        } else {
+            String::from("Halted")
+        };
+
+        let mut lines = vec![
+            format!("A: {}", computer.register_a),
+            format!("B: {}", computer.register_b),
+            format!("C: {}", computer.register_c),
+            String::new(),
+        ];
+        let mut highlights = vec![];
+        for (ip, instruction) in program.chunks(2).enumerate() {
+            let ip = 2 * ip;
+            let operand = instruction.get(1).copied().unwrap_or(0);
+            let line = format!("{ip:>4}  {}", disassemble(instruction[0], operand));
+            if ip == computer.ip {
+                highlights.push((lines.len(), 0..line.len()));
+            }
+            lines.push(line);
+        }
+        lines.push(String::new());
+        lines.push(format!("Output: {}", computer.out));
+        Frame{status, lines, highlights}
+    }
+}
@@ -0,0 +1,183 @@
+use std::io::{self, Write};
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::style::{Attribute, Print, SetAttribute};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+
+use crate::{Frame, Simulation, Stepper};
+
+/// How far running until a condition goes before giving up, since some
+/// simulations never finish.
+const MAX_RUN: usize = 10_000_000;
+
+const HELP: &str = "→/space step  ← back  g jump  u run until  e end  Home start  q quit";
+
+/// Restores the terminal when dropped, even if a step panics.
+struct RawScreen;
+
+impl RawScreen {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawScreen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+pub fn run<S: Simulation>(title: &str, sim: S) -> io::Result<()> {
+    let _screen = RawScreen::enter()?;
+    let mut stepper = Stepper::new(sim);
+    let mut message = String::new();
+
+    loop {
+        draw(title, &stepper, &message, HELP)?;
+        message.clear();
+
+        let Some(key) = next_key()? else {continue;};
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Right | KeyCode::Char(' ') | KeyCode::Char('l') => {
+                if stepper.forward() {continue;}
+                message = String::from("The simulation has finished");
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                stepper.back();
+            }
+            KeyCode::Home => {
+                stepper.go_to(0);
+            }
+            KeyCode::Char('e') | KeyCode::End => {
+                if stepper.run_to_end(MAX_RUN) {continue;}
+                message = format!("Still running after {MAX_RUN} steps");
+            }
+            KeyCode::Char('g') => {
+                let prompt = "Jump by +N or -N steps, or to step N: ";
+                let Some(text) = read_line(title, &stepper, prompt)? else {continue;};
+                let text = text.trim();
+                let parsed = match text.strip_prefix('+') {
+                    Some(n) => n.parse().map(|n: usize| stepper.step().saturating_add(n)),
+                    None if text.starts_with('-') => text.parse().map(|n: isize| stepper.step().saturating_add_signed(n)),
+                    None => text.parse(),
+                };
+                match parsed {
+                    Ok(target) => {
+                        if stepper.go_to(target) < target {
+                            message = format!("The simulation finished at step {}", stepper.step());
+                        }
+                    }
+                    Err(_) => message = format!("Not a step: {text}"),
+                }
+            }
+            KeyCode::Char('u') => {
+                let conditions = S::conditions();
+                let choices: Vec<String> = conditions.iter().enumerate()
+                    .map(|(i, condition)| format!("{}) {}", i + 1, condition.name))
+                    .collect();
+                let prompt = format!("Run until {}: ", choices.join("  "));
+                let Some(text) = read_line(title, &stepper, &prompt)? else {continue;};
+                let condition = text.trim().parse::<usize>().ok()
+                    .and_then(|n| conditions.get(n.wrapping_sub(1)));
+                let Some(condition) = condition else {
+                    message = format!("No such condition: {}", text.trim());
+                    continue;
+                };
+                if !stepper.run_until(condition, MAX_RUN) {
+                    message = if stepper.is_finished() {
+                        format!("The simulation finished before {}", condition.name)
+                    } else {
+                        format!("Gave up waiting for {} after {MAX_RUN} steps", condition.name)
+                    };
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Waits for a key press, ignoring other events.
+fn next_key() -> io::Result<Option<KeyEvent>> {
+    match event::read()? {
+        Event::Key(key) if key.kind != KeyEventKind::Release => Ok(Some(key)),
+        _ => Ok(None),
+    }
+}
+
+/// Reads a line of text at the bottom of the screen. Returns `None` if it was
+/// cancelled with Esc.
+fn read_line<S: Simulation>(title: &str, stepper: &Stepper<S>, prompt: &str) -> io::Result<Option<String>> {
+    let mut text = String::new();
+    loop {
+        draw(title, stepper, "", &format!("{prompt}{text}"))?;
+        let Some(key) = next_key()? else {continue;};
+        match key.code {
+            KeyCode::Enter => return Ok(Some(text)),
+            KeyCode::Esc => return Ok(None),
+            KeyCode::Backspace => {
+                text.pop();
+            }
+            KeyCode::Char(c) => text.push(c),
+            _ => {}
+        }
+    }
+}
+
+fn draw<S: Simulation>(title: &str, stepper: &Stepper<S>, message: &str, footer: &str) -> io::Result<()> {
+    let (width, height) = terminal::size()?;
+    let (width, height) = (width as usize, height as usize);
+    let frame = stepper.state().frame();
+    let mut out = io::stdout().lock();
+    queue!(out, Clear(ClearType::All), MoveTo(0, 0))?;
+
+    let finished = if stepper.is_finished() {", finished"} else {""};
+    let header = format!("{title}  step {}{finished}  {}", stepper.step(), frame.status);
+    queue!(out, SetAttribute(Attribute::Bold), Print(clip(&header, 0, width)), SetAttribute(Attribute::Reset))?;
+
+    let rows = height.saturating_sub(3);
+    let (top, left) = viewport(&frame, rows, width);
+    for (row, line) in frame.lines.iter().skip(top).take(rows).enumerate() {
+        queue!(out, MoveTo(0, (row + 1) as u16))?;
+        draw_line(&mut out, &frame, top + row, line, left, width)?;
+    }
+
+    queue!(out, MoveTo(0, height.saturating_sub(2) as u16), Print(clip(message, 0, width)))?;
+    queue!(out, MoveTo(0, height.saturating_sub(1) as u16), Print(clip(footer, 0, width)))?;
+    out.flush()
+}
+
+/// Prints the visible part of a line, with its highlighted columns reversed.
+fn draw_line(out: &mut impl Write, frame: &Frame, line_num: usize, line: &str, left: usize, width: usize) -> io::Result<()> {
+    for (col, c) in line.chars().enumerate().skip(left).take(width) {
+        let highlighted = frame.highlights.iter()
+            .any(|(i, cols)| *i == line_num && cols.contains(&col));
+        if highlighted {
+            queue!(out, SetAttribute(Attribute::Reverse), Print(c), SetAttribute(Attribute::Reset))?;
+        } else {
+            queue!(out, Print(c))?;
+        }
+    }
+    Ok(())
+}
+
+/// The first line and column to show, so that the first highlight is in view
+/// when the frame doesn't fit.
+fn viewport(frame: &Frame, rows: usize, cols: usize) -> (usize, usize) {
+    let Some((i, range)) = frame.highlights.first() else {
+        return (0, 0);
+    };
+    let max_width = frame.lines.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+    let top = i.saturating_sub(rows / 2).min(frame.lines.len().saturating_sub(rows));
+    let left = range.start.saturating_sub(cols / 2).min(max_width.saturating_sub(cols));
+    (top, left)
+}
+
+fn clip(s: &str, left: usize, width: usize) -> String {
+    s.chars().skip(left).take(width).collect()
+}
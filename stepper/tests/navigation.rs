@@ -0,0 +1,127 @@
+use aoc2024::{day06, day15, day17};
+use stepper::sims::{Patrol, Program, Warehouse};
+use stepper::{Simulation, Stepper};
+
+const PATROL: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+const LOOPING_PATROL: &str = ".#..\n.^.#\n#...\n..#.\n";
+
+const WAREHOUSE: &str = "\
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^
+";
+
+const PROGRAM: &str = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n";
+
+#[test]
+fn patrol_visits_the_cells_counted_by_part_1() {
+    let grid = grid::rows(PATROL);
+    let mut stepper = Stepper::new(Patrol::new(&grid).unwrap());
+    assert!(stepper.run_to_end(100_000));
+    assert_eq!(stepper.state().guard, None);
+    assert_eq!(Ok(stepper.state().visited()), day06::solve_part_1(&grid));
+}
+
+#[test]
+fn patrol_runs_until_it_loops() {
+    let grid = grid::rows(LOOPING_PATROL);
+    let mut stepper = Stepper::new(Patrol::new(&grid).unwrap());
+    let conditions = Patrol::conditions();
+    let looping = conditions.iter().find(|c| c.name.contains("repeating")).unwrap();
+    assert!(stepper.run_until(looping, 100));
+    assert!(!stepper.run_to_end(10_000));
+}
+
+#[test]
+fn stepping_back_past_checkpoints_replays_the_same_states() {
+    let grid = grid::rows(LOOPING_PATROL);
+    let mut stepper = Stepper::new(Patrol::new(&grid).unwrap());
+    let mut states = vec![stepper.state().guard];
+    while stepper.step() < 2500 {
+        assert!(stepper.forward());
+        states.push(stepper.state().guard);
+    }
+
+    for target in [2499, 2000, 1000, 999, 1, 0, 1500] {
+        assert_eq!(stepper.go_to(target), target);
+        assert_eq!(stepper.state().guard, states[target]);
+    }
+    assert!(stepper.back());
+    assert_eq!(stepper.state().guard, states[1499]);
+    assert_eq!(stepper.jump(-500), 999);
+    assert_eq!(stepper.jump(2), 1001);
+    assert_eq!(stepper.state().guard, states[1001]);
+}
+
+#[test]
+fn warehouse_ends_with_the_part_2_answer() {
+    let input = day15::parse_input(WAREHOUSE.as_bytes()).unwrap();
+    let mut stepper = Stepper::new(Warehouse::new(&input));
+    assert_eq!(stepper.go_to(usize::MAX), input.moves.len());
+    assert!(stepper.is_finished());
+    let gps = day15::gps_sum(&stepper.state().warehouse.grid, b'[');
+    assert_eq!(gps, day15::solve_part_2(&input));
+
+    assert_eq!(stepper.go_to(0), 0);
+    assert!(!stepper.is_finished());
+    assert!(!stepper.back());
+    assert_eq!(stepper.state().warehouse.grid, day15::WideWarehouse::new(&input).grid);
+}
+
+#[test]
+fn warehouse_runs_until_a_box_is_pushed() {
+    let input = day15::parse_input(WAREHOUSE.as_bytes()).unwrap();
+    let mut stepper = Stepper::new(Warehouse::new(&input));
+    let conditions = Warehouse::conditions();
+    let pushed = conditions.iter().find(|c| c.name.contains("pushes")).unwrap();
+    // The first move, left, pushes the two boxes next to the robot.
+    assert!(stepper.run_until(pushed, 100));
+    assert_eq!(stepper.step(), 1);
+}
+
+#[test]
+fn program_outputs_the_part_1_answer() {
+    let input = day17::parse_input(PROGRAM.as_bytes()).unwrap();
+    let mut stepper = Stepper::new(Program::new(&input));
+    let conditions = Program::conditions();
+    let output = conditions.iter().find(|c| c.name.contains("outputs")).unwrap();
+
+    // The loop is three instructions, one of which outputs a digit.
+    assert!(stepper.run_until(output, 100));
+    assert_eq!(stepper.step(), 2);
+    assert_eq!(stepper.state().computer.out, "4");
+    assert!(stepper.run_until(output, 100));
+    assert_eq!(stepper.step(), 5);
+
+    assert!(stepper.run_to_end(1000));
+    assert_eq!(stepper.state().computer.out, day17::solve_part_1(&input).unwrap());
+}
+
+#[test]
+fn program_frame_highlights_the_current_instruction() {
+    let input = day17::parse_input(PROGRAM.as_bytes()).unwrap();
+    let mut stepper = Stepper::new(Program::new(&input));
+    stepper.forward();
+    let frame = stepper.state().frame();
+    assert_eq!(frame.lines[0], "A: 364");
+    let (line, cols) = frame.highlights[0].clone();
+    assert_eq!(&frame.lines[line][cols], "   2  out A");
+}